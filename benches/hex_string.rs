@@ -0,0 +1,29 @@
+#[macro_use]
+extern crate criterion;
+extern crate hex_string;
+
+use criterion::{black_box, Criterion};
+use hex_string::HexString;
+
+fn digest_bytes(count: usize) -> Vec<u8> {
+    (0..count).map(|i| (i * 31 + 7) as u8).collect()
+}
+
+fn as_bytes(c: &mut Criterion) {
+    let digest = HexString::from_bytes(&digest_bytes(32));
+    let blob = HexString::from_bytes(&digest_bytes(64 * 1024));
+
+    c.bench_function("as_bytes 32 bytes", |b| b.iter(|| black_box(&digest).as_bytes()));
+    c.bench_function("as_bytes 64 KiB", |b| b.iter(|| black_box(&blob).as_bytes()));
+}
+
+fn from_bytes(c: &mut Criterion) {
+    let digest = digest_bytes(32);
+    let blob = digest_bytes(64 * 1024);
+
+    c.bench_function("from_bytes 32 bytes", |b| b.iter(|| HexString::from_bytes(black_box(&digest))));
+    c.bench_function("from_bytes 64 KiB", |b| b.iter(|| HexString::from_bytes(black_box(&blob))));
+}
+
+criterion_group!(benches, as_bytes, from_bytes);
+criterion_main!(benches);
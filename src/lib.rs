@@ -4,22 +4,78 @@
 //! applicaions, like sha256sum, return byte strings. I was unable to find an obvious way to handle
 //! this in rust, so this module provides a clear well-defined HexString, loaders from a regular
 //! string of hex values and from a vector of bytes, and output representations in both forms.
+//!
+//! The crate only requires `alloc`. The `std` feature, which is enabled by default, may be
+//! disabled to build for `no_std` targets.
+
+#![no_std]
 
-use std::collections::{ HashSet };
-use std::result;
-use std::str::FromStr;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+#[macro_use]
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::convert::TryFrom;
+use core::iter::FromIterator;
+use core::fmt;
+use core::ops::{Add, Deref, Not, Range};
+use core::result;
+use core::slice;
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::io;
 
 /// HexString provides a structured representation of a hex string. It is guaranteed to be a valid
 /// string, whether initialized from a string or from a byte vector.
-#[derive(Clone, Debug, PartialEq)]
+///
+/// Equality and hashing compare the hex characters exactly, so two HexStrings which differ only in
+/// case are not equal. Constructors preserve the case of their input, so call `canonicalize` to
+/// normalize a HexString to lower case before comparing or hashing it.
+///
+/// Ordering compares the hex characters lexicographically. For HexStrings of the same length and
+/// case, such as a list of digests, this is the same as ordering by byte value. Mixing lengths or
+/// cases gives an ordering which is consistent, but not meaningful.
+///
+/// The default HexString is empty, the same as `HexString::empty()`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct HexString(String);
 
+/// The errors which can occur while constructing or converting a HexString. This implements
+/// `Error`, so it converts with `?` into `Box<dyn Error>` or an `anyhow::Error`. New variants may
+/// be added in future releases, so a `match` outside of this crate needs a wildcard arm.
+///
+/// ```compile_fail,E0004
+/// extern crate hex_string;
+///
+/// use hex_string::HexStringError;
+///
+/// fn describe(err: HexStringError) -> &'static str {
+///     match err {
+///         HexStringError::InvalidStringLength => "odd length",
+///     }
+/// }
+///
+/// fn main() {
+///     let _ = describe(HexStringError::InvalidStringLength);
+/// }
+/// ```
 #[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
 pub enum HexStringError {
-    /// There was an invalid character in the hex string
+    /// A single character passed to `hexchar_to_nibble` was not a hex character
     #[error("Encountered invalid character: '{0}'")]
     InvalidCharacter(char),
 
+    /// There was an invalid character in the hex string. `index` is the byte offset of the
+    /// character in the input, which is also its character index since every character before the
+    /// first invalid one is ASCII.
+    #[error("Encountered invalid character '{ch}' at index {index}")]
+    InvalidCharacterAt { ch: char, index: usize },
+
     /// All hex strings must be an even length in order to represent bytes because each two
     /// characters represents one byte
     #[error("String length was odd, but it must be even")]
@@ -30,64 +86,148 @@ pub enum HexStringError {
     /// `nibble_to_hexchar`, or in the case of a bug in this module.
     #[error("Weird error, tried to convert nible outside of 0-15(inclusive), byte value '{0}'")]
     InvalidNibble(u8),
+
+    /// A separated hex string must have exactly two hex characters, or one byte, between each
+    /// separator
+    #[error("Expected a group of two hex characters, but found '{0}'")]
+    InvalidGroup(String),
+
+    /// The caller provided an output buffer which cannot hold all of the decoded bytes. The first
+    /// value is the number of bytes needed, the second is the size of the buffer.
+    #[error("Output buffer is too small, needed {0} bytes but only {1} are available")]
+    BufferTooSmall(usize, usize),
+
+    /// A requested range, such as a slice of bytes, does not fit within the HexString. The second
+    /// value is the length that the range was checked against.
+    #[error("Range {0:?} is out of bounds for a length of {1}")]
+    OutOfBounds(Range<usize>, usize),
+
+    /// An operation required a specific number of bytes, such as combining two HexStrings of
+    /// equal length. The first value is the expected length, the second is the length found.
+    #[error("Expected a length of {0} bytes, but found {1}")]
+    LengthMismatch(usize, usize),
+
+    /// The HexString has more bytes than fit into the requested integer type. The first value is
+    /// the length of the HexString, the second is the width of the integer, both in bytes.
+    #[error("A value of {0} bytes does not fit in a {1} byte integer")]
+    IntegerOverflow(usize, usize),
+
+    /// The input is longer than the caller allowed, such as untrusted input from a network request.
+    /// The first value is the limit, the second is the length of the input, both in decoded bytes.
+    /// An input with an odd number of characters is counted as if it had one more.
+    #[error("Input of {1} bytes is longer than the limit of {0} bytes")]
+    TooLong(usize, usize),
+
+    /// An input had to be made of whole units, such as bits which form whole bytes. The first value
+    /// is the length of the input, the second is the size of a unit.
+    #[error("A length of {0} is not a multiple of {1}")]
+    NotMultipleOf(usize, usize),
+
+    /// A line passed to `from_sum_line` is not in the format written by `sha256sum` and similar
+    /// tools, a digest followed by a space, a space or `*`, and a filename, or it starts with `\`
+    /// but the filename is not escaped correctly
+    #[error("Malformed checksum line: '{0}'")]
+    MalformedSumLine(String),
+
+    /// A string passed to `from_base64` was not valid standard base64. Requires the `base64`
+    /// feature.
+    #[cfg(feature = "base64")]
+    #[error("Invalid base64: {0}")]
+    InvalidBase64(base64::DecodeError),
 }
 
 type Result<A> = result::Result<A, HexStringError>;
 
+/// The case of the hex characters a-f, for choosing how bytes are encoded or formatted
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HexCase {
+    /// The characters a-f, which is what `from_bytes` produces
+    Lower,
+    /// The characters A-F
+    Upper,
+}
 
-/// Given a character, convert it into a u8 in the range 0-15 (inclusive).
-///
-/// Note that Rust does not have an obvious nibble data type, so we approximate with the lower 4
-/// bits of a u8.
+
+/// The largest value which fits in a nibble
+pub const MAX_NIBBLE: u8 = 0x0f;
+
+/// Nibble is a value in the range 0-15 (inclusive), which is the value of a single hex character.
+/// Since the range is checked when a Nibble is created, converting one to a character cannot
+/// fail.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Nibble(u8);
+
+impl Nibble {
+    /// Create a Nibble from a value.
+    ///
+    /// This will return InvalidNibble if the value is greater than MAX_NIBBLE.
+    pub fn new(value: u8) -> Result<Nibble> {
+        if value > MAX_NIBBLE { return Err(HexStringError::InvalidNibble(value)) }
+        Ok(Nibble(value))
+    }
+
+    /// Create a Nibble from a hex character. Upper-case characters map to the same values as their
+    /// lower-case counterparts.
+    ///
+    /// This will return InvalidCharacter if the character is not in the range 0-9, a-f, or A-F.
+    pub fn from_char(c: char) -> Result<Nibble> {
+        const_hexchar_to_nibble(c).map(Nibble).ok_or(HexStringError::InvalidCharacter(c))
+    }
+
+    /// Return the lower-case hex character for this Nibble
+    pub fn to_char(self) -> char {
+        HEX_CHARS[self.0 as usize] as char
+    }
+
+    /// Return the value of this Nibble, in the range 0-15
+    pub fn value(self) -> u8 {
+        self.0
+    }
+}
+
+impl From<Nibble> for u8 {
+    fn from(nibble: Nibble) -> u8 {
+        nibble.0
+    }
+}
+
+
+/// Given a character, convert it into a u8 in the range 0-15 (inclusive). This is a thin wrapper
+/// around `Nibble::from_char`.
 ///
-/// This will raise InvalidCharacte if the provided character is not in the range 0-9 or a-f
-/// (lower-case only).
+/// This will raise InvalidCharacter if the provided character is not in the range 0-9, a-f, or
+/// A-F. Upper-case characters map to the same values as their lower-case counterparts.
 pub fn hexchar_to_nibble(c: &char) -> Result<u8> {
+    Nibble::from_char(*c).map(Nibble::value)
+}
+
+/// A `const fn` version of `hexchar_to_nibble`, which converts using arithmetic rather than a
+/// lookup. This returns None for any character outside the range 0-9, a-f, or A-F.
+pub const fn const_hexchar_to_nibble(c: char) -> Option<u8> {
     match c {
-        '0' => Ok(0),
-        '1' => Ok(1),
-        '2' => Ok(2),
-        '3' => Ok(3),
-        '4' => Ok(4),
-        '5' => Ok(5),
-        '6' => Ok(6),
-        '7' => Ok(7),
-        '8' => Ok(8),
-        '9' => Ok(9),
-        'a' => Ok(10),
-        'b' => Ok(11),
-        'c' => Ok(12),
-        'd' => Ok(13),
-        'e' => Ok(14),
-        'f' => Ok(15),
-        _ => Err(HexStringError::InvalidCharacter(*c))
+        '0'..='9' => Some(c as u8 - b'0'),
+        'a'..='f' => Some(c as u8 - b'a' + 10),
+        'A'..='F' => Some(c as u8 - b'A' + 10),
+        _ => None,
     }
 }
 
 
 /// Given a nibble (a u8 value in the range 0-15), convert it to its corresponding character
-/// representation.
+/// representation. This is a thin wrapper around `Nibble::new` and `Nibble::to_char`.
 ///
 /// This will raise InvalidNibble if the value provided is outside the range 0-15.
 pub fn nibble_to_hexchar(b: &u8) -> Result<char>  {
+    Nibble::new(*b).map(Nibble::to_char)
+}
+
+/// A `const fn` version of `nibble_to_hexchar`, which converts using arithmetic rather than a
+/// lookup. This returns None for any value outside the range 0-15.
+pub const fn const_nibble_to_hexchar(b: u8) -> Option<char> {
     match b {
-        0 => Ok('0'),
-        1 => Ok('1'),
-        2 => Ok('2'),
-        3 => Ok('3'),
-        4 => Ok('4'),
-        5 => Ok('5'),
-        6 => Ok('6'),
-        7 => Ok('7'),
-        8 => Ok('8'),
-        9 => Ok('9'),
-        10 => Ok('a'),
-        11 => Ok('b'),
-        12 => Ok('c'),
-        13 => Ok('d'),
-        14 => Ok('e'),
-        15 => Ok('f'),
-        _ => Err(HexStringError::InvalidNibble(*b)),
+        0..=9 => Some((b'0' + b) as char),
+        10..=15 => Some((b'a' + b - 10) as char),
+        _ => None,
     }
 }
 
@@ -97,55 +237,456 @@ pub fn u8_to_hex_string(b: &u8) -> [char; 2] {
     fn fmt_error(b: &u8) -> String {
         format!("should never have an invalid nibble here. parts: {:?}, {:?}", (b & 0xf0) >> 4, b & 0x0f)
     }
-    let upper = nibble_to_hexchar(&((b & 0xf0) >> 4)).expect(&fmt_error(b));
-    let lower = nibble_to_hexchar(&(b & 0x0f)).expect(&fmt_error(b));
+    let upper = nibble_to_hexchar(&((b & 0xf0) >> 4)).unwrap_or_else(|_| panic!("{}", fmt_error(b)));
+    let lower = nibble_to_hexchar(&(b & 0x0f)).unwrap_or_else(|_| panic!("{}", fmt_error(b)));
     [upper, lower]
 }
 
 
+/// The lower-case hex character for each nibble, indexed by the nibble's value
+const HEX_CHARS: [u8; 16] = *b"0123456789abcdef";
+
+/// Convert a byte to the ASCII bytes of its two-character hex string representation. Unlike
+/// `u8_to_hex_string`, this is a plain table lookup, since every nibble of a byte is valid.
+pub const fn u8_to_hex_bytes(b: u8) -> [u8; 2] {
+    [HEX_CHARS[(b >> 4) as usize], HEX_CHARS[(b & 0x0f) as usize]]
+}
+
+/// Write the lower-case hex characters of `bytes` directly into `w`, such as a String being built
+/// or a `fmt::Formatter`, without constructing a HexString. The characters are written a small
+/// buffer at a time.
+pub fn write_hex<W: fmt::Write>(w: &mut W, bytes: &[u8]) -> fmt::Result {
+    let mut buf = [0; 64];
+    for chunk in bytes.chunks(buf.len() / 2) {
+        for (pair, b) in buf.chunks_mut(2).zip(chunk) {
+            pair.copy_from_slice(&u8_to_hex_bytes(*b));
+        }
+        w.write_str(core::str::from_utf8(&buf[..chunk.len() * 2]).expect("hex characters are always valid UTF-8"))?;
+    }
+    Ok(())
+}
+
+
+#[doc(hidden)]
+pub const fn __hex_nibble(b: u8) -> u8 {
+    match const_hexchar_to_nibble(b as char) {
+        Some(n) => n,
+        None => panic!("hex literal contains a character outside of 0-9, a-f, and A-F"),
+    }
+}
+
+#[doc(hidden)]
+pub const fn __hex_decoded_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    if !bytes.len().is_multiple_of(2) {
+        panic!("hex literal has an odd length, but it must be even");
+    }
+    let mut i = 0;
+    while i < bytes.len() {
+        __hex_nibble(bytes[i]);
+        i += 1;
+    }
+    bytes.len() / 2
+}
+
+#[doc(hidden)]
+pub const fn __hex_decode<const N: usize>(s: &str) -> [u8; N] {
+    let bytes = s.as_bytes();
+    let mut out = [0; N];
+    let mut i = 0;
+    while i < N {
+        out[i] = (__hex_nibble(bytes[2 * i]) << 4) | __hex_nibble(bytes[2 * i + 1]);
+        i += 1;
+    }
+    out
+}
+
+/// Decode a hex string literal into a `[u8; N]` array at compile time.
+///
+/// The literal follows the same rules as `HexString::from_string`, but violations are reported as
+/// compile errors instead of a runtime `Result`:
+///
+/// * a literal of odd length fails with "hex literal has an odd length, but it must be even"
+/// * a literal containing anything other than 0-9, a-f, and A-F fails with "hex literal contains
+///   a character outside of 0-9, a-f, and A-F"
+///
+/// ```compile_fail,E0080
+/// #[macro_use]
+/// extern crate hex_string;
+///
+/// fn main() {
+///     let _ = hex!("abc");
+/// }
+/// ```
+///
+/// ```compile_fail,E0080
+/// #[macro_use]
+/// extern crate hex_string;
+///
+/// fn main() {
+///     let _ = hex!("abcdefg0");
+/// }
+/// ```
+#[macro_export]
+macro_rules! hex {
+    ($s:literal) => {{
+        const BYTES: [u8; $crate::__hex_decoded_len($s)] = $crate::__hex_decode($s);
+        BYTES
+    }};
+}
+
+/// Build a `HexString` from a string literal which has been validated at compile time.
+///
+/// This fails to compile in exactly the same cases as `hex!`. The case of the literal is
+/// preserved, just as with `HexString::from_string`.
+///
+/// ```compile_fail,E0080
+/// #[macro_use]
+/// extern crate hex_string;
+///
+/// fn main() {
+///     let _ = hex_string!("0x00");
+/// }
+/// ```
+#[macro_export]
+macro_rules! hex_string {
+    ($s:literal) => {{
+        const _LEN: usize = $crate::__hex_decoded_len($s);
+        let _ = _LEN;
+        $crate::HexString::from_string($s).expect("hex_string! literal was validated at compile time")
+    }};
+}
+
+
+impl HexStringError {
+    /// Move the index of an InvalidCharacterAt error along by `offset`, for errors found in a
+    /// substring of the original input
+    fn offset_by(self, offset: usize) -> HexStringError {
+        match self {
+            HexStringError::InvalidCharacterAt { ch, index } => HexStringError::InvalidCharacterAt { ch, index: index + offset },
+            err => err,
+        }
+    }
+}
+
+
+fn validate_chars(s: &str) -> Result<()> {
+    for (index, ch) in s.char_indices() {
+        if ! ch.is_ascii_hexdigit() {
+            return Err(HexStringError::InvalidCharacterAt { ch, index });
+        }
+    }
+    Ok(())
+}
+
+/// Check that `s` is a valid hex string, returning the same error that `HexString::from_string`
+/// would, without constructing a HexString. This allows a caller to report why a string is
+/// invalid without allocating.
+///
+/// This will return InvalidCharacterAt if any non-hex character is detected, and an
+/// InvalidStringLength error if the length is not even.
+pub fn validate(s: &str) -> Result<()> {
+    validate_chars(s)?;
+
+    // Every character is now known to be ASCII, so the byte length is the character count.
+    if !s.len().is_multiple_of(2) { return Err(HexStringError::InvalidStringLength) }
+
+    Ok(())
+}
+
+/// Remove a leading `0x` or `0X`, if there is one
+fn strip_hex_prefix(s: &str) -> &str {
+    s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")).unwrap_or(s)
+}
+
+/// Check whether `s` is a valid hex string, exactly as `HexString::from_string` would, without
+/// allocating anything. This is a cheap filter for when the HexString itself is not needed.
+pub fn is_valid_hex(s: &str) -> bool {
+    s.len().is_multiple_of(2) && s.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Find every character in `s` which is not a hex character, along with its byte index, rather
+/// than stopping at the first one as `validate` does. This lets a linting tool highlight every
+/// problem at once. The length is not checked, so an odd-length string of hex characters returns
+/// an empty vector.
+pub fn find_invalid(s: &str) -> Vec<(usize, char)> {
+    s.char_indices().filter(|(_, ch)| !ch.is_ascii_hexdigit()).collect()
+}
+
+
 impl HexString {
+    /// Initialize a HexString which represents no bytes at all. This function cannot fail.
+    pub fn empty() -> HexString {
+        HexString(String::new())
+    }
+
     /// Initialize a HexString from an actual hex string. The input string must be of an even
     /// length (since it takes two hex characters to represent a byte) and must contain only
-    /// characters in the range 0-9 and a-f.
+    /// characters in the range 0-9, a-f, and A-F.
+    ///
+    /// The case of the input is preserved, so `as_string` returns exactly the string that was
+    /// provided.
     ///
-    /// This will return an InvalidStringLength error if the length is not even, and
-    /// InvalidCharacter if any non-hex character is detected.
+    /// This will return InvalidCharacterAt if any non-hex character is detected, and an
+    /// InvalidStringLength error if the length is not even. Characters are checked first so that a
+    /// multibyte character is reported as itself rather than as a confusing length error.
     pub fn from_string(s: &str) -> Result<HexString> {
-        if s.len() % 2 != 0 { return Err(HexStringError::InvalidStringLength) }
-
-        let mut valid_chars = HashSet::new();
-        valid_chars.insert('0');
-        valid_chars.insert('1');
-        valid_chars.insert('2');
-        valid_chars.insert('3');
-        valid_chars.insert('4');
-        valid_chars.insert('5');
-        valid_chars.insert('6');
-        valid_chars.insert('7');
-        valid_chars.insert('8');
-        valid_chars.insert('9');
-        valid_chars.insert('a');
-        valid_chars.insert('b');
-        valid_chars.insert('c');
-        valid_chars.insert('d');
-        valid_chars.insert('e');
-        valid_chars.insert('f');
-
-        for c in s.chars() {
-            if ! valid_chars.contains(&c) {
-                return Err(HexStringError::InvalidCharacter(c));
-            }
-        }
+        validate(s)?;
+        Ok(HexString(String::from(s)))
+    }
+
+    /// Initialize a HexString exactly as in `from_string`, but only if it represents at most
+    /// `max_bytes` bytes. This bounds the allocation made for untrusted input, such as from a
+    /// network request.
+    ///
+    /// This will return TooLong, before validating or allocating anything, if `s` has more than
+    /// `max_bytes * 2` characters. The length it reports is in bytes, rounded up for an odd number
+    /// of characters.
+    pub fn from_string_bounded(s: &str, max_bytes: usize) -> Result<HexString> {
+        if s.len() > max_bytes.saturating_mul(2) { return Err(HexStringError::TooLong(max_bytes, s.len().div_ceil(2))) }
+        Self::from_string(s)
+    }
+
+    /// Initialize a HexString exactly as in `from_string`, but only if it represents exactly
+    /// `expected_bytes` bytes, such as when parsing a fixed-width field.
+    ///
+    /// This will return the same errors as `from_string` for an invalid string, and then
+    /// LengthMismatch if it is valid but has the wrong length.
+    pub fn from_string_expecting(s: &str, expected_bytes: usize) -> Result<HexString> {
+        validate(s)?;
+        if s.len() / 2 != expected_bytes { return Err(HexStringError::LengthMismatch(expected_bytes, s.len() / 2)) }
         Ok(HexString(String::from(s)))
     }
 
+    /// Initialize a HexString from hex characters given as raw ASCII bytes, such as read from a
+    /// file, without first checking that they are valid UTF-8. Otherwise this is the same as
+    /// `from_string`.
+    ///
+    /// This will return InvalidCharacterAt if any byte is not a hex character, with the byte
+    /// shown as the character with that code point, and InvalidStringLength if the length is not
+    /// even.
+    pub fn from_ascii_hex(bytes: &[u8]) -> Result<HexString> {
+        if let Some(index) = bytes.iter().position(|b| !b.is_ascii_hexdigit()) {
+            return Err(HexStringError::InvalidCharacterAt { ch: bytes[index] as char, index });
+        }
+        if !bytes.len().is_multiple_of(2) { return Err(HexStringError::InvalidStringLength) }
+        Ok(HexString(bytes.iter().map(|b| *b as char).collect()))
+    }
+
+    /// Initialize a HexString from a hex string which may have ASCII whitespace at either end, such
+    /// as a value read from a file which ends with `\n` or `\r\n`. The whitespace is trimmed and
+    /// the remainder is handled exactly as in `from_string`, which stays strict. The index of an
+    /// invalid character counts the leading whitespace.
+    pub fn from_string_trimmed(s: &str) -> Result<HexString> {
+        let start_trimmed = s.trim_ascii_start();
+        Self::from_string(start_trimmed.trim_ascii_end()).map_err(|err| err.offset_by(s.len() - start_trimmed.len()))
+    }
+
+    /// Initialize a HexString from a hex string which may begin with a `0x` or `0X` prefix, as is
+    /// common in debugger output and C source. The prefix is stripped and the remainder is handled
+    /// exactly as in `from_string`, so a bare `0x` produces an empty HexString. The index of an
+    /// invalid character counts the prefix.
+    pub fn from_string_prefixed(s: &str) -> Result<HexString> {
+        let unprefixed = strip_hex_prefix(s);
+        Self::from_string(unprefixed).map_err(|err| err.offset_by(s.len() - unprefixed.len()))
+    }
+
+    /// Initialize a HexString from a hex string which may contain ASCII whitespace anywhere, such
+    /// as hex pasted from documentation or multi-line certificate output. All whitespace is
+    /// removed before the string is handled as in `from_string`.
+    ///
+    /// This will return InvalidCharacterAt if any other non-hex character is detected, with the
+    /// index into `s` including the whitespace, and InvalidStringLength if the number of hex
+    /// characters is not even.
+    pub fn from_string_lenient(s: &str) -> Result<HexString> {
+        let mut hex = String::with_capacity(s.len());
+        for (index, ch) in s.char_indices() {
+            if ch.is_ascii_whitespace() { continue }
+            if !ch.is_ascii_hexdigit() { return Err(HexStringError::InvalidCharacterAt { ch, index }) }
+            hex.push(ch);
+        }
+
+        if !hex.len().is_multiple_of(2) { return Err(HexStringError::InvalidStringLength) }
+        Ok(HexString(hex))
+    }
+
+    /// Initialize a HexString from a hex string which may be of odd length, such as a short hex
+    /// integer. An odd length string is treated as if it had a leading `0`, so `f` becomes `0f`.
+    ///
+    /// This will return InvalidCharacterAt if any non-hex character is detected. The index is into
+    /// `s`, not counting the padding.
+    pub fn from_string_padded(s: &str) -> Result<HexString> {
+        validate_chars(s)?;
+        if s.len().is_multiple_of(2) {
+            Ok(HexString(String::from(s)))
+        } else {
+            Ok(HexString(format!("0{}", s)))
+        }
+    }
+
+    /// Initialize a HexString from a hex string which has a separator between each byte, such as
+    /// a MAC address (`de:ad:be:ef`) or a fingerprint (`AB CD EF`). The separators are removed and
+    /// the case of the input is preserved.
+    ///
+    /// This will return InvalidCharacterAt if any group contains a non-hex character, with the
+    /// index into `s` including the separators, and InvalidGroup if any group is not exactly two
+    /// characters long.
+    pub fn from_separated(s: &str, sep: char) -> Result<HexString> {
+        let mut hex = String::with_capacity(s.len());
+        if s.is_empty() { return Ok(HexString(hex)) }
+
+        let mut offset = 0;
+        for group in s.split(sep) {
+            validate_chars(group).map_err(|err| err.offset_by(offset))?;
+            if group.len() != 2 { return Err(HexStringError::InvalidGroup(String::from(group))) }
+            hex.push_str(group);
+            offset += group.len() + sep.len_utf8();
+        }
+        Ok(HexString(hex))
+    }
+
+    /// Parse a line of the output of `sha256sum` or a similar tool, such as
+    /// `cbbbc6e1...  filename.txt`, returning the digest and the filename. The digest is separated
+    /// from the filename by two spaces in text mode or by ` *` in binary mode. A trailing `\n` or
+    /// `\r\n` is ignored.
+    ///
+    /// Like coreutils, a line which starts with `\` has an escaped filename, in which `\\`, `\n`,
+    /// and `\r` stand for a backslash, a newline, and a carriage return. This is how a filename
+    /// containing any of those is written, so that the line can be read back.
+    ///
+    /// This will return MalformedSumLine if the line does not have a digest, a separator, and a
+    /// filename, or if an escaped filename contains any other escape, and the same errors as
+    /// `from_string` if the digest is not a valid hex string.
+    pub fn from_sum_line(line: &str) -> Result<(HexString, String)> {
+        let malformed = || HexStringError::MalformedSumLine(String::from(line));
+        let trimmed = line.strip_suffix('\n').map(|l| l.strip_suffix('\r').unwrap_or(l)).unwrap_or(line);
+        let (escaped, trimmed) = match trimmed.strip_prefix('\\') {
+            Some(rest) => (true, rest),
+            None => (false, trimmed),
+        };
+        let (digest, rest) = trimmed.split_once(' ').ok_or_else(malformed)?;
+        let filename = rest.strip_prefix(' ').or_else(|| rest.strip_prefix('*')).ok_or_else(malformed)?;
+        if digest.is_empty() || filename.is_empty() { return Err(malformed()) }
+        let digest = HexString::from_string(digest).map_err(|err| err.offset_by(escaped as usize))?;
+        let filename = if escaped { unescape_sum_filename(filename).ok_or_else(malformed)? } else { String::from(filename) };
+        Ok((digest, filename))
+    }
+
     /// Initialize a hex strign from a binary vector. This function cannot fail.
+    ///
+    /// The String is allocated once, with room for at least `2 * v.len()` hex characters, so it
+    /// never reallocates while the bytes are encoded.
     pub fn from_bytes(v: &[u8]) -> HexString {
-        HexString(v.iter().map(|b| u8_to_hex_string(b)).fold(String::new(), |mut acc, s| {
-            acc.push(s[0]);
-            acc.push(s[1]);
-            acc
-        }))
+        HexString::from_bytes_with_capacity(v, v.len() * 2)
+    }
+
+    /// Initialize a HexString from a binary vector, allocating the String once with room for at
+    /// least `capacity` hex characters, or `2 * bytes.len()` if that is more. A caller which will
+    /// go on to append more, such as with `+`, can size the String for the final value up front, so
+    /// that it never reallocates. This function cannot fail.
+    pub fn from_bytes_with_capacity(bytes: &[u8], capacity: usize) -> HexString {
+        let mut hex = String::with_capacity(capacity.max(bytes.len() * 2));
+        encode_into(bytes, &mut hex);
+        HexString(hex)
+    }
+
+    /// Initialize a HexString from the bytes of ASCII text, such as an identifier. This is the
+    /// inverse of `as_ascii_lossy` for printable text.
+    ///
+    /// This will return InvalidCharacterAt if `s` contains any character which is not ASCII.
+    pub fn from_ascii(s: &str) -> Result<HexString> {
+        match s.char_indices().find(|(_, ch)| !ch.is_ascii()) {
+            Some((index, ch)) => Err(HexStringError::InvalidCharacterAt { ch, index }),
+            None => Ok(HexString::from_bytes(s.as_bytes())),
+        }
+    }
+
+    /// Initialize a HexString from bits, with the most significant bit of each byte first. This is
+    /// the inverse of `to_bits`.
+    ///
+    /// This will return NotMultipleOf if the number of bits is not a multiple of 8.
+    pub fn from_bits(bits: &[bool]) -> Result<HexString> {
+        if !bits.len().is_multiple_of(8) { return Err(HexStringError::NotMultipleOf(bits.len(), 8)) }
+        Ok(bits.chunks(8).map(|byte| byte.iter().fold(0, |acc, bit| (acc << 1) | *bit as u8)).collect())
+    }
+
+    /// Initialize a HexString from the (high, low) nibbles of each byte, such as for BCD data. This
+    /// is the inverse of `nibble_pairs`.
+    ///
+    /// This will return InvalidNibble if any value is outside the range 0-15.
+    pub fn from_nibble_pairs(pairs: &[(u8, u8)]) -> Result<HexString> {
+        let mut hex = String::with_capacity(pairs.len() * 2);
+        for (high, low) in pairs {
+            hex.push(Nibble::new(*high)?.to_char());
+            hex.push(Nibble::new(*low)?.to_char());
+        }
+        Ok(HexString(hex))
+    }
+
+    /// Initialize a hex string from a binary vector, encoding the characters a-f in the given case.
+    /// This function cannot fail.
+    pub fn from_bytes_cased(v: &[u8], case: HexCase) -> HexString {
+        let mut hex = HexString::from_bytes(v);
+        if case == HexCase::Upper { hex.0.make_ascii_uppercase() }
+        hex
+    }
+
+    /// Initialize a HexString from `bytes` random bytes generated by the thread-local random
+    /// number generator, such as for a token. Requires the `rand` and `std` features.
+    #[cfg(all(feature = "rand", feature = "std"))]
+    pub fn random(bytes: usize) -> HexString {
+        HexString::random_with(&mut rand::thread_rng(), bytes)
+    }
+
+    /// Initialize a HexString from `bytes` random bytes generated by `rng`, which allows a seeded
+    /// generator to be used for reproducible values. Requires the `rand` feature.
+    #[cfg(feature = "rand")]
+    pub fn random_with<R: rand::RngCore + ?Sized>(rng: &mut R, bytes: usize) -> HexString {
+        let mut buf = vec![0; bytes];
+        rng.fill_bytes(&mut buf);
+        HexString::from_bytes(&buf)
+    }
+
+    /// Initialize a HexString from a fixed-size array of bytes, so that the length of the digest
+    /// is checked by the compiler. This function cannot fail.
+    pub fn from_bytes_exact<const N: usize>(bytes: &[u8; N]) -> HexString {
+        HexString::from_bytes(bytes)
+    }
+
+    /// Initialize a HexString from the big-endian bytes of an integer. All eight bytes are
+    /// encoded, so leading zero bytes are preserved.
+    pub fn from_u64_be(v: u64) -> HexString {
+        HexString::from_bytes(&v.to_be_bytes())
+    }
+
+    /// Initialize a HexString from the little-endian bytes of an integer. All eight bytes are
+    /// encoded, so trailing zero bytes are preserved.
+    pub fn from_u64_le(v: u64) -> HexString {
+        HexString::from_bytes(&v.to_le_bytes())
+    }
+
+    /// Initialize a HexString from the big-endian bytes of an integer. All sixteen bytes are
+    /// encoded, so leading zero bytes are preserved.
+    pub fn from_u128_be(v: u128) -> HexString {
+        HexString::from_bytes(&v.to_be_bytes())
+    }
+
+    /// Initialize a HexString from the little-endian bytes of an integer. All sixteen bytes are
+    /// encoded, so trailing zero bytes are preserved.
+    pub fn from_u128_le(v: u128) -> HexString {
+        HexString::from_bytes(&v.to_le_bytes())
+    }
+
+    /// Initialize a HexString from the standard padded base64 alphabet (RFC 4648 section 4), such
+    /// as a digest copied from a Subresource Integrity attribute. Requires the `base64` feature.
+    ///
+    /// This will return InvalidBase64 if the string is not valid base64, including if it uses the
+    /// URL-safe alphabet or omits the padding.
+    #[cfg(feature = "base64")]
+    pub fn from_base64(s: &str) -> Result<HexString> {
+        base64::Engine::decode(&base64::engine::general_purpose::STANDARD, s)
+            .map(|bytes| HexString::from_bytes(&bytes))
+            .map_err(HexStringError::InvalidBase64)
     }
 
     /// Return a String representation
@@ -153,87 +694,3115 @@ impl HexString {
         self.0.clone()
     }
 
+    /// Return a String representation with the characters a-f in upper case, regardless of the
+    /// case used to construct this HexString
+    pub fn as_string_upper(&self) -> String {
+        self.0.to_ascii_uppercase()
+    }
+
+    /// Return a String representation with the characters a-f in lower case, regardless of the
+    /// case used to construct this HexString
+    pub fn as_string_lower(&self) -> String {
+        self.0.to_ascii_lowercase()
+    }
+
+    /// Return a String representation with the characters a-f in the given case, regardless of the
+    /// case used to construct this HexString
+    pub fn as_string_cased(&self, case: HexCase) -> String {
+        match case {
+            HexCase::Lower => self.as_string_lower(),
+            HexCase::Upper => self.as_string_upper(),
+        }
+    }
+
+    /// Return a new HexString with the characters a-f in upper case, such as for canonicalizing
+    /// before comparing or hashing
+    pub fn to_upper(&self) -> HexString {
+        HexString(self.as_string_upper())
+    }
+
+    /// Return a new HexString with the characters a-f in lower case, such as for canonicalizing
+    /// before comparing or hashing
+    pub fn to_lower(&self) -> HexString {
+        HexString(self.as_string_lower())
+    }
+
+    /// Normalize the hex characters to lower case in place, which is the case `from_bytes`
+    /// produces, so that `==`, `Hash`, and `Ord` treat values which differ only in case alike
+    pub fn canonicalize(&mut self) {
+        self.0.make_ascii_lowercase();
+    }
+
+    /// Consume the HexString and return it with the hex characters normalized to lower case. See
+    /// `canonicalize`.
+    pub fn into_canonical(mut self) -> HexString {
+        self.canonicalize();
+        self
+    }
+
     /// Return a &str slice
     pub fn as_str(&self) -> &str {
         self.0.as_str()
     }
 
+    /// Return the hex characters as a borrowed Cow, for callers which accept either a borrowed or
+    /// an owned string, without cloning
+    pub fn as_cow(&self) -> Cow<'_, str> {
+        Cow::Borrowed(&self.0)
+    }
+
+    /// Consume the HexString and return its hex characters, without cloning them
+    pub fn into_string(mut self) -> String {
+        // take rather than move, since HexString implements Drop with the zeroize feature
+        core::mem::take(&mut self.0)
+    }
+
+    /// Return the bytes encoded with the standard padded base64 alphabet (RFC 4648 section 4).
+    /// Requires the `base64` feature.
+    #[cfg(feature = "base64")]
+    pub fn to_base64(&self) -> String {
+        base64::Engine::encode(&base64::engine::general_purpose::STANDARD, self.as_bytes())
+    }
+
+    /// Return the bytes encoded by `hex::encode`, for passing to code which already uses the `hex`
+    /// crate. This is always in lower case, regardless of the case used to construct this
+    /// HexString. Requires the `hex-compat` feature.
+    #[cfg(feature = "hex-compat")]
+    pub fn to_hex_crate_string(&self) -> String {
+        hex::encode(self.as_bytes())
+    }
+
+    /// Return the number of bytes represented, which is half of the number of hex characters.
+    ///
+    /// Note that this shadows `str::len` through `Deref`. Use `char_len` for the number of hex
+    /// characters.
+    pub fn len(&self) -> usize {
+        self.0.len() / 2
+    }
+
+    /// Return true if this HexString represents no bytes at all
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Return the number of hex characters
+    pub fn char_len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Guess which common hash algorithm produced this HexString, based only on its length in
+    /// bytes, for tooling which labels a value such as "SHA-256: ...". This returns None for any
+    /// length which is not the length of MD5, SHA-1, SHA-224, SHA-256, SHA-384, or SHA-512.
+    ///
+    /// This is only a guess, since other algorithms share these lengths, such as SHA3-256 and
+    /// BLAKE2s with SHA-256.
+    pub fn guess_algorithm(&self) -> Option<&'static str> {
+        match self.len() {
+            16 => Some("MD5"),
+            20 => Some("SHA-1"),
+            28 => Some("SHA-224"),
+            32 => Some("SHA-256"),
+            48 => Some("SHA-384"),
+            64 => Some("SHA-512"),
+            _ => None,
+        }
+    }
+
+    /// Check that this HexString is exactly `bytes` bytes long, such as confirming that a value is
+    /// a 32 byte SHA-256 digest, and return it unchanged if so.
+    ///
+    /// This will return LengthMismatch if the length is not as expected.
+    pub fn expect_len(self, bytes: usize) -> Result<HexString> {
+        if self.len() != bytes { return Err(HexStringError::LengthMismatch(bytes, self.len())) }
+        Ok(self)
+    }
+
     /// Return a byte representation
     pub fn as_bytes(&self) -> Vec<u8> {
-        let mut i = self.0.chars();
-        let mut octets: Vec<Vec<char>> = Vec::new();
+        let mut bytes = Vec::with_capacity(self.len());
+        bytes.extend(self.bytes_iter());
+        bytes
+    }
 
-        let mut octet: Vec<char> = i.by_ref().take(2).collect();
-        while octet.len() != 0 {
-            octets.push(octet.clone());
-            octet = i.by_ref().take(2).collect();
+    /// Consume the HexString and return its decoded bytes. The bytes are decoded in place, into the
+    /// buffer which held the hex characters, so both representations are never held at once. The
+    /// leftover hex characters beyond the decoded bytes are cleared before the buffer is returned.
+    pub fn into_bytes(self) -> Vec<u8> {
+        let len = self.len();
+        let mut bytes = self.into_string().into_bytes();
+        for i in 0..len {
+            bytes[i] = decode_byte(bytes[i * 2], bytes[i * 2 + 1]);
         }
+        bytes[len..].fill(0);
+        bytes.truncate(len);
+        bytes
+    }
 
-        fn to_byte(octet: Vec<char>) -> u8 {
-            let upper = hexchar_to_nibble(&octet[0]).expect("There should never be an invalid hexchar here");
-            let lower = hexchar_to_nibble(&octet[1]).expect("There should never be an invalid hexchar here");
-            (upper << 4) | lower
-        }
+    /// Return an iterator which lazily decodes each byte, without allocating
+    pub fn bytes_iter(&self) -> Bytes<'_> {
+        Bytes { pairs: self.0.as_bytes().chunks(2) }
+    }
 
-        octets.into_iter().map(|octet| to_byte(octet)).collect()
+    /// Decode only the byte at index `i`, without decoding any of the others. `Index` cannot be
+    /// implemented for HexString, since it must return a reference and the bytes are only
+    /// computed on demand.
+    ///
+    /// This will return OutOfBounds if `i` is not less than `len()`.
+    pub fn byte_at(&self, i: usize) -> Result<u8> {
+        if i >= self.len() { return Err(HexStringError::OutOfBounds(i..i + 1, self.len())) }
+        let hex = self.0.as_bytes();
+        Ok(decode_byte(hex[i * 2], hex[i * 2 + 1]))
     }
-}
 
-/// Implementing the FromStr trait will let it be combined better with other crates
-/// It refers the implementation to the existing `from_string` function.
-impl FromStr for HexString {
-    type Err = HexStringError;
+    /// Return the bits of the bytes, with the most significant bit of each byte first, such as for
+    /// a bitset
+    pub fn to_bits(&self) -> Vec<bool> {
+        self.bytes_iter().flat_map(|b| (0..8).rev().map(move |bit| b & (1 << bit) != 0)).collect()
+    }
 
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        Self::from_string(s)
+    /// Return how many times the byte `needle` appears in the decoded bytes, such as for counting
+    /// the zero bytes in a key
+    pub fn count_byte(&self, needle: u8) -> usize {
+        self.bytes_iter().filter(|b| *b == needle).count()
     }
-}
 
+    /// Return true if the byte `needle` appears anywhere in the decoded bytes
+    pub fn contains_byte(&self, needle: u8) -> bool {
+        self.bytes_iter().any(|b| b == needle)
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Return the byte offset at which `needle` first appears in the decoded bytes, such as for
+    /// locating a marker within a binary blob. As with `str::find`, an empty needle is found at
+    /// offset 0.
+    pub fn find_bytes(&self, needle: &[u8]) -> Option<usize> {
+        if needle.is_empty() { return Some(0) }
+        self.as_bytes().windows(needle.len()).position(|window| window == needle)
+    }
 
-    fn byte_repr() -> Vec<u8> { vec![203, 187, 198, 225, 155, 230, 62, 252, 221, 120, 50, 125, 45, 248, 80, 217, 35, 117, 175, 106, 3, 147, 79, 53, 228, 123, 208, 45, 27, 73, 108, 12] }
-    fn string_repr() -> String { String::from("cbbbc6e19be63efcdd78327d2df850d92375af6a03934f35e47bd02d1b496c0c") }
+    /// Return true if the decoded bytes begin with `prefix`, such as for checking a magic number.
+    /// This compares whole bytes, so it never matches a prefix which is offset by a nibble.
+    pub fn starts_with_bytes(&self, prefix: &[u8]) -> bool {
+        prefix.len() <= self.len() && self.bytes_iter().zip(prefix).all(|(b, p)| b == *p)
+    }
 
-    #[test]
-    fn it_converts_bytes_to_string() {
-        let res = HexString::from_bytes(&byte_repr());
-        assert_eq!(*res.as_string(), string_repr());
+    /// Return true if the decoded bytes end with `suffix`. This compares whole bytes, so it never
+    /// matches a suffix which is offset by a nibble.
+    pub fn ends_with_bytes(&self, suffix: &[u8]) -> bool {
+        suffix.len() <= self.len() && self.bytes_iter().rev().zip(suffix.iter().rev()).all(|(b, s)| b == *s)
     }
 
-    #[test]
-    fn it_converts_bytes_to_str_slice() {
-        let res = HexString::from_bytes(&byte_repr());
-        assert_eq!(res.as_str(), string_repr());
+    /// Return the (high, low) nibbles of each byte, such as for BCD data, in which each nibble is
+    /// a decimal digit
+    pub fn nibble_pairs(&self) -> Vec<(u8, u8)> {
+        self.bytes_iter().map(|b| (b >> 4, b & 0x0f)).collect()
     }
 
-    #[test]
-    fn it_converts_string_to_bytes() {
-        match HexString::from_string(&string_repr()) {
-            Err(err) => panic!(format!("error encoding from string: {:?}", err)),
-            Ok(res) => assert_eq!(res.as_bytes(), byte_repr()),
+    /// Return the value, in the range 0-15, of only the hex character at index `i`, for protocols
+    /// which address values 4 bits at a time.
+    ///
+    /// This will return OutOfBounds if `i` is not less than `char_len()`.
+    pub fn nibble_at(&self, i: usize) -> Result<u8> {
+        match self.0.as_bytes().get(i) {
+            Some(c) => Ok(hexchar_to_nibble(&(*c as char)).expect("There should never be an invalid hexchar here")),
+            None => Err(HexStringError::OutOfBounds(i..i + 1, self.char_len())),
         }
     }
 
-    #[test]
-    fn it_rejects_invalid_strings() {
-        match HexString::from_string("abcdefg") {
-            Err(_err) => (),
-            Ok(_) => panic!("did not reject a 'g' in the string"),
+    /// Return an iterator over the value, in the range 0-15, of each hex character in order
+    pub fn nibbles(&self) -> impl Iterator<Item = u8> + '_ {
+        self.0.chars().map(|c| hexchar_to_nibble(&c).expect("There should never be an invalid hexchar here"))
+    }
+
+    /// Decode the bytes into a caller-provided buffer, returning the number of bytes written. This
+    /// avoids allocating when the caller already has a buffer, such as a `[u8; 32]` for a SHA-256
+    /// digest. Any bytes in the buffer beyond the decoded length are left untouched.
+    ///
+    /// This will return BufferTooSmall if `out` is shorter than `len()`, in which case nothing is
+    /// written.
+    pub fn decode_into(&self, out: &mut [u8]) -> Result<usize> {
+        if out.len() < self.len() { return Err(HexStringError::BufferTooSmall(self.len(), out.len())) }
+
+        for (out_byte, byte) in out.iter_mut().zip(self.bytes_iter()) {
+            *out_byte = byte;
         }
+        Ok(self.len())
     }
 
-    #[test]
-    fn it_can_be_parsed_using_the_parse_function() {
-        let _hex_s = string_repr().parse::<HexString>()
-            .expect("string_repr example should be parsable");
+    /// Decode the bytes and write them to `w`, such as a file or socket, without building a
+    /// `Vec<u8>` of the whole value. Bytes are decoded into a small stack buffer and written a
+    /// buffer at a time. Requires the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn write_bytes_to<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        let mut buf = [0; 256];
+        let mut bytes = self.bytes_iter();
+        loop {
+            let count = buf.iter_mut().zip(&mut bytes).map(|(out, byte)| *out = byte).count();
+            if count == 0 { return Ok(()) }
+            w.write_all(&buf[..count])?;
+        }
     }
 
-    #[test]
+    /// Decode the bytes into a fixed-size array, such as a `[u8; 32]` for a SHA-256 digest,
+    /// without allocating.
+    ///
+    /// This will return LengthMismatch if the HexString is not exactly `N` bytes long.
+    pub fn to_array<const N: usize>(&self) -> Result<[u8; N]> {
+        if self.len() != N { return Err(HexStringError::LengthMismatch(N, self.len())) }
+        let mut out = [0; N];
+        self.decode_into(&mut out)?;
+        Ok(out)
+    }
+
+    /// Interpret the bytes as a big-endian integer. A HexString shorter than eight bytes is treated
+    /// as if it had leading zero bytes.
+    ///
+    /// This will return IntegerOverflow if the HexString is longer than eight bytes.
+    pub fn to_u64_be(&self) -> Result<u64> {
+        self.to_integer_bytes(true).map(u64::from_be_bytes)
+    }
+
+    /// Interpret the bytes as a little-endian integer. A HexString shorter than eight bytes is
+    /// treated as if it had trailing zero bytes.
+    ///
+    /// This will return IntegerOverflow if the HexString is longer than eight bytes.
+    pub fn to_u64_le(&self) -> Result<u64> {
+        self.to_integer_bytes(false).map(u64::from_le_bytes)
+    }
+
+    /// Interpret the bytes as a big-endian integer. A HexString shorter than sixteen bytes is
+    /// treated as if it had leading zero bytes.
+    ///
+    /// This will return IntegerOverflow if the HexString is longer than sixteen bytes.
+    pub fn to_u128_be(&self) -> Result<u128> {
+        self.to_integer_bytes(true).map(u128::from_be_bytes)
+    }
+
+    /// Interpret the bytes as a little-endian integer. A HexString shorter than sixteen bytes is
+    /// treated as if it had trailing zero bytes.
+    ///
+    /// This will return IntegerOverflow if the HexString is longer than sixteen bytes.
+    pub fn to_u128_le(&self) -> Result<u128> {
+        self.to_integer_bytes(false).map(u128::from_le_bytes)
+    }
+
+    /// Decode into an `N` byte integer representation, zero-filling the most significant end
+    fn to_integer_bytes<const N: usize>(&self, big_endian: bool) -> Result<[u8; N]> {
+        if self.len() > N { return Err(HexStringError::IntegerOverflow(self.len(), N)) }
+        let mut out = [0; N];
+        if big_endian {
+            self.decode_into(&mut out[N - self.len()..])?;
+        } else {
+            self.decode_into(&mut out)?;
+        }
+        Ok(out)
+    }
+
+    /// Return a new HexString with the order of the bytes reversed, for converting between
+    /// little-endian and big-endian representations. This is not the same as reversing the hex
+    /// characters, which would also swap the nibbles within each byte.
+    pub fn reverse_bytes(&self) -> HexString {
+        let mut hex = String::with_capacity(self.0.len());
+        for pair in self.0.as_bytes().chunks(2).rev() {
+            hex.push(pair[0] as char);
+            hex.push(pair[1] as char);
+        }
+        HexString(hex)
+    }
+
+    /// Return a new HexString without the leading zero bytes, for hex which represents an
+    /// integer. At least one byte is always kept, so a value which is entirely zero bytes becomes
+    /// `00`, like the integer zero. An empty HexString stays empty.
+    pub fn trim_leading_zero_bytes(&self) -> HexString {
+        let zero_bytes = self.0.as_bytes().chunks(2).take_while(|pair| pair == b"00").count();
+        let trimmed = zero_bytes.min(self.len().saturating_sub(1));
+        HexString(String::from(&self.0[trimmed * 2..]))
+    }
+
+    /// Return a new HexString with zero bytes prepended until it is `total_bytes` long, such as for
+    /// normalizing an integer to a fixed width. A HexString which is already at least
+    /// `total_bytes` long is returned unchanged.
+    pub fn pad_left_bytes(&self, total_bytes: usize) -> HexString {
+        let padding = total_bytes.saturating_sub(self.len());
+        let mut hex = String::with_capacity((self.len() + padding) * 2);
+        hex.extend(core::iter::repeat_n('0', padding * 2));
+        hex.push_str(&self.0);
+        HexString(hex)
+    }
+
+    /// Return a new HexString with zero bytes appended until it is `total_bytes` long, such as for
+    /// zero-extending a key. A HexString which is already at least `total_bytes` long is returned
+    /// unchanged.
+    pub fn pad_right_bytes(&self, total_bytes: usize) -> HexString {
+        let padding = total_bytes.saturating_sub(self.len());
+        let mut hex = String::with_capacity((self.len() + padding) * 2);
+        hex.push_str(&self.0);
+        hex.extend(core::iter::repeat_n('0', padding * 2));
+        HexString(hex)
+    }
+
+    /// Format the hex characters with `sep` between every `group_bytes` bytes, such as
+    /// `de:ad:be:ef` or `dead beef`. No separator is added before the first group or after the
+    /// last, so grouping single bytes is the inverse of `from_separated`. A `group_bytes` of zero
+    /// means no grouping at all.
+    pub fn to_grouped(&self, group_bytes: usize, sep: char) -> String {
+        if group_bytes == 0 { return self.as_string() }
+
+        let mut grouped = String::with_capacity(self.0.len() + self.len() / group_bytes * sep.len_utf8());
+        for (i, group) in self.chunks_bytes(group_bytes).enumerate() {
+            if i > 0 { grouped.push(sep) }
+            grouped.push_str(&group.0);
+        }
+        grouped
+    }
+
+    /// Interpret the bytes as ASCII text, such as when debugging a text protocol. Any byte which is
+    /// not printable ASCII is shown as `.`, as in the gutter of `hexdump`.
+    pub fn as_ascii_lossy(&self) -> String {
+        self.as_ascii_lossy_with('.')
+    }
+
+    /// Interpret the bytes as ASCII text, showing any byte which is not printable ASCII as
+    /// `placeholder`
+    pub fn as_ascii_lossy_with(&self, placeholder: char) -> String {
+        self.bytes_iter().map(|b| printable_or(b, placeholder)).collect()
+    }
+
+    /// Format a line in the style of `sha256sum` and similar tools: the digest in lower case, then
+    /// two spaces in text mode or ` *` in binary mode, then the filename. No newline is added. This
+    /// is the inverse of `from_sum_line`.
+    ///
+    /// Like coreutils, a filename containing a backslash, a newline, or a carriage return is written
+    /// with those escaped as `\\`, `\n`, and `\r`, and the line starts with `\` to mark it.
+    pub fn to_sum_line(&self, filename: &str, binary: bool) -> String {
+        let escape = filename.contains(['\\', '\n', '\r']);
+        let mut line = String::with_capacity(1 + self.0.len() + 2 + filename.len());
+        if escape { line.push('\\') }
+        line.push_str(&self.as_string_lower());
+        line.push_str(if binary { " *" } else { "  " });
+        if !escape {
+            line.push_str(filename);
+        } else {
+            for c in filename.chars() {
+                match c {
+                    '\\' => line.push_str("\\\\"),
+                    '\n' => line.push_str("\\n"),
+                    '\r' => line.push_str("\\r"),
+                    c => line.push(c),
+                }
+            }
+        }
+        line
+    }
+
+    /// Format the bytes in the style of `hexdump -C`, with sixteen bytes per row. See
+    /// `hexdump_with`.
+    pub fn hexdump(&self) -> String {
+        self.hexdump_with(16)
+    }
+
+    /// Format the bytes in the style of `hexdump -C`, with `bytes_per_row` bytes per row. Each
+    /// row begins with the offset of its first byte, then the bytes in two columns, and finally
+    /// an ASCII gutter in which non-printable bytes are shown as `.`. A short final row is padded
+    /// so that its gutter lines up with the rows above it. Every row, including the last, ends
+    /// with a newline.
+    ///
+    /// This panics if `bytes_per_row` is zero.
+    pub fn hexdump_with(&self, bytes_per_row: usize) -> String {
+        use core::fmt::Write;
+
+        assert!(bytes_per_row > 0, "hexdump_with requires at least one byte per row");
+        let bytes = self.as_bytes();
+        let mut out = String::new();
+
+        for (row_index, row) in bytes.chunks(bytes_per_row).enumerate() {
+            write!(out, "{:08x}  ", row_index * bytes_per_row).expect("writing to a String cannot fail");
+            for i in 0..bytes_per_row {
+                match row.get(i) {
+                    Some(b) => write!(out, "{:02x} ", b).expect("writing to a String cannot fail"),
+                    None => out.push_str("   "),
+                }
+                if i + 1 == bytes_per_row / 2 { out.push(' ') }
+            }
+
+            out.push_str(" |");
+            out.extend(row.iter().map(|b| printable_or(*b, '.')));
+            out.push_str("|\n");
+        }
+        out
+    }
+
+    /// Return a new HexString with the bytes of `other` appended to the bytes of this one
+    pub fn concat(&self, other: &HexString) -> HexString {
+        let mut hex = String::with_capacity(self.0.len() + other.0.len());
+        hex.push_str(&self.0);
+        hex.push_str(&other.0);
+        HexString(hex)
+    }
+
+    /// Return a new HexString with the bytes repeated `n` times, such as for building a test
+    /// pattern like `deadbeefdeadbeef`. Note that this shadows `str::repeat` through `Deref`.
+    pub fn repeat(&self, n: usize) -> HexString {
+        HexString(self.0.repeat(n))
+    }
+
+    /// Return a new HexString covering only the bytes in `range`, such as the first four bytes of
+    /// a digest. The range is in bytes, not hex characters.
+    ///
+    /// This will return OutOfBounds if the range extends past the end of the HexString or if it
+    /// starts after it ends.
+    pub fn slice_bytes(&self, range: Range<usize>) -> Result<HexString> {
+        if range.start > range.end || range.end > self.len() {
+            return Err(HexStringError::OutOfBounds(range, self.len()));
+        }
+        Ok(HexString(String::from(&self.0[range.start * 2..range.end * 2])))
+    }
+
+    /// Split into two HexStrings at the byte `byte_index`, such as separating a fixed-size header
+    /// from a payload, or a nonce from ciphertext. The first HexString holds the bytes before
+    /// `byte_index`, and the second holds the rest.
+    ///
+    /// This will return OutOfBounds, with the range of the first HexString, if `byte_index` is
+    /// past the end of the HexString.
+    pub fn split_at_byte(&self, byte_index: usize) -> Result<(HexString, HexString)> {
+        if byte_index > self.len() { return Err(HexStringError::OutOfBounds(0..byte_index, self.len())) }
+        let (head, tail) = self.0.split_at(byte_index * 2);
+        Ok((HexString(String::from(head)), HexString(String::from(tail))))
+    }
+
+    /// Return an iterator over successive HexStrings of `chunk_bytes` bytes each, such as for
+    /// processing a value block by block. The last HexString is shorter if the length is not a
+    /// multiple of `chunk_bytes`.
+    ///
+    /// This panics if `chunk_bytes` is zero.
+    pub fn chunks_bytes(&self, chunk_bytes: usize) -> impl Iterator<Item = HexString> + '_ {
+        assert!(chunk_bytes > 0, "chunks_bytes requires at least one byte per chunk");
+        self.0.as_bytes().chunks(chunk_bytes * 2).map(|chunk| {
+            HexString(String::from(core::str::from_utf8(chunk).expect("a chunk of hex characters is always valid UTF-8")))
+        })
+    }
+
+    /// Keep only the first `bits` bits, for algorithms such as truncated MACs whose output is not a
+    /// whole number of bytes. The result is `bits / 8` bytes long, rounded up, and the bits of the
+    /// final byte beyond `bits` are cleared.
+    ///
+    /// This will return OutOfBounds, with the range and length both in bits, if `bits` is more
+    /// than the number of bits available.
+    pub fn truncate_bits(&self, bits: usize) -> Result<HexString> {
+        if bits > self.len() * 8 { return Err(HexStringError::OutOfBounds(0..bits, self.len() * 8)) }
+        let mut bytes: Vec<u8> = self.bytes_iter().take(bits.div_ceil(8)).collect();
+        if let Some(last) = bytes.last_mut() {
+            if !bits.is_multiple_of(8) { *last &= 0xff << (8 - bits % 8) }
+        }
+        Ok(HexString::from_bytes(&bytes))
+    }
+
+    /// Return the first `bytes` bytes, in the style of an abbreviated commit hash. Unlike
+    /// `slice_bytes`, requesting more bytes than are available returns the whole HexString.
+    pub fn short(&self, bytes: usize) -> HexString {
+        HexString(String::from(&self.0[..bytes.min(self.len()) * 2]))
+    }
+
+    /// XOR the bytes of two HexStrings of equal length together, returning the result as a new
+    /// HexString.
+    ///
+    /// This will return LengthMismatch if the HexStrings are not the same length.
+    pub fn xor(&self, other: &HexString) -> Result<HexString> {
+        self.combine_bytes(other, |a, b| a ^ b)
+    }
+
+    /// AND the bytes of two HexStrings of equal length together, such as for applying a mask,
+    /// returning the result as a new HexString.
+    ///
+    /// This will return LengthMismatch if the HexStrings are not the same length.
+    pub fn bitand(&self, other: &HexString) -> Result<HexString> {
+        self.combine_bytes(other, |a, b| a & b)
+    }
+
+    /// OR the bytes of two HexStrings of equal length together, such as for setting flag bits,
+    /// returning the result as a new HexString.
+    ///
+    /// This will return LengthMismatch if the HexStrings are not the same length.
+    pub fn bitor(&self, other: &HexString) -> Result<HexString> {
+        self.combine_bytes(other, |a, b| a | b)
+    }
+
+    /// XOR successive chunks of `out_bytes` bytes together, producing a shorter value of
+    /// `out_bytes` bytes, such as for a lightweight fingerprint. This is not a cryptographic hash.
+    ///
+    /// An empty HexString has no chunks to combine, so it folds to `out_bytes` zero bytes, the
+    /// identity of XOR.
+    ///
+    /// This will return NotMultipleOf if the length is not a multiple of `out_bytes`, rather than
+    /// padding the last chunk, or if `out_bytes` is zero.
+    pub fn fold_xor(&self, out_bytes: usize) -> Result<HexString> {
+        if out_bytes == 0 || !self.len().is_multiple_of(out_bytes) {
+            return Err(HexStringError::NotMultipleOf(self.len(), out_bytes));
+        }
+        let mut folded = vec![0; out_bytes];
+        for (i, b) in self.bytes_iter().enumerate() {
+            folded[i % out_bytes] ^= b;
+        }
+        Ok(HexString::from_bytes(&folded))
+    }
+
+    /// Invert every bit of every byte, such as for computing the complement of a bitmask,
+    /// returning the result as a new HexString. This is also available as the `!` operator on a
+    /// `&HexString`.
+    pub fn not(&self) -> HexString {
+        self.bytes_iter().map(|b| !b).collect()
+    }
+
+    /// Treating the bytes as a big-endian number, shift it left by `bits` bits. The width is
+    /// preserved, so bits shifted past the first byte are discarded and the last bytes are filled
+    /// with zeros. Shifting by the whole width or more produces all zero bytes.
+    pub fn shl(&self, bits: usize) -> HexString {
+        let bytes = self.as_bytes();
+        let byte_at = |i: usize| bytes.get(i).cloned().unwrap_or(0);
+        let (byte_shift, bit_shift) = (bits / 8, (bits % 8) as u32);
+        (0..bytes.len()).map(|i| {
+            let high = byte_at(i + byte_shift) << bit_shift;
+            let low = byte_at(i + byte_shift + 1).checked_shr(8 - bit_shift).unwrap_or(0);
+            high | low
+        }).collect()
+    }
+
+    /// Treating the bytes as a big-endian number, shift it right by `bits` bits. The width is
+    /// preserved, so bits shifted past the last byte are discarded and the first bytes are filled
+    /// with zeros. Shifting by the whole width or more produces all zero bytes.
+    pub fn shr(&self, bits: usize) -> HexString {
+        let bytes = self.as_bytes();
+        let byte_before = |i: usize, shift: usize| i.checked_sub(shift).map(|j| bytes[j]).unwrap_or(0);
+        let (byte_shift, bit_shift) = (bits / 8, (bits % 8) as u32);
+        (0..bytes.len()).map(|i| {
+            let low = byte_before(i, byte_shift) >> bit_shift;
+            let high = byte_before(i, byte_shift + 1).checked_shl(8 - bit_shift).unwrap_or(0);
+            high | low
+        }).collect()
+    }
+
+    fn combine_bytes<F: Fn(u8, u8) -> u8>(&self, other: &HexString, op: F) -> Result<HexString> {
+        if self.len() != other.len() { return Err(HexStringError::LengthMismatch(self.len(), other.len())) }
+        Ok(self.bytes_iter().zip(other.bytes_iter()).map(|(a, b)| op(a, b)).collect())
+    }
+
+    /// Compute the CRC-32 checksum (the IEEE polynomial used by zip, gzip, and PNG) of the decoded
+    /// bytes, for a quick integrity check such as of a manifest entry. This is not a cryptographic
+    /// hash. Requires the `crc32` feature.
+    #[cfg(feature = "crc32")]
+    pub fn crc32(&self) -> u32 {
+        !self.bytes_iter().fold(!0, |crc, b| CRC32_TABLE[((crc ^ b as u32) & 0xff) as usize] ^ (crc >> 8))
+    }
+
+    /// Check whether `other`, which may be in either case and may begin with a `0x` or `0X`
+    /// prefix, represents the same bytes, such as when checking a computed digest against an
+    /// expected value from any source. Nothing is allocated.
+    ///
+    /// This will return an error, exactly as `from_string_prefixed` would, if `other` is not a
+    /// valid hex string.
+    pub fn equals_hex_str(&self, other: &str) -> Result<bool> {
+        let unprefixed = strip_hex_prefix(other);
+        validate(unprefixed).map_err(|err| err.offset_by(other.len() - unprefixed.len()))?;
+        Ok(self.0.eq_ignore_ascii_case(unprefixed))
+    }
+
+    /// Compare two HexStrings while ignoring the case of the hex characters, so that `ABCD` and
+    /// `abcd` are considered to be the same value
+    pub fn eq_ignore_case(&self, other: &HexString) -> bool {
+        self.0.eq_ignore_ascii_case(&other.0)
+    }
+
+    /// Compare the decoded bytes of two HexStrings in constant time, for checking a computed
+    /// digest against an expected one without leaking timing information. Unlike `==`, this
+    /// ignores the case of the hex characters.
+    ///
+    /// The characters are decoded with arithmetic rather than branches, and every byte is compared
+    /// rather than returning at the first difference. This is best effort, since the compiler
+    /// gives no guarantee about the timing of the code it generates. Only the contents are
+    /// protected; HexStrings of different lengths return false immediately.
+    pub fn ct_eq(&self, other: &HexString) -> bool {
+        self.len() == other.len() && ct_eq_bytes(self.ct_bytes_iter(), other.ct_bytes_iter())
+    }
+
+    /// Check whether the decoded bytes are the same as `bytes`, such as a digest which was just
+    /// computed, without allocating. Like `ct_eq`, the characters are decoded without branching and
+    /// every byte is compared, with the same best-effort caveat, except that a length mismatch
+    /// returns false immediately.
+    pub fn matches_bytes(&self, bytes: &[u8]) -> bool {
+        self.len() == bytes.len() && ct_eq_bytes(self.ct_bytes_iter(), bytes.iter().cloned())
+    }
+
+    /// Count how many byte positions differ from `other`, such as for reporting how damaged a value
+    /// is. Like `ct_eq`, the characters are decoded without branching and each difference is
+    /// counted with arithmetic rather than a comparison, so that the time taken is intended to
+    /// depend only on the length. This has the same best-effort caveat as `ct_eq`.
+    ///
+    /// This will return LengthMismatch if `other` is not the same length.
+    pub fn byte_diff_count(&self, other: &[u8]) -> Result<usize> {
+        if self.len() != other.len() { return Err(HexStringError::LengthMismatch(self.len(), other.len())) }
+        Ok(self.ct_bytes_iter().zip(other).fold(0, |count, (a, b)| {
+            // the top bit of `diff | -diff` is set exactly when `diff` is not zero
+            let diff = a ^ b;
+            core::hint::black_box(count + ((diff | diff.wrapping_neg()) >> 7) as usize)
+        }))
+    }
+
+    /// Decode each byte with `ct_decode_byte`, for the constant-time comparisons
+    fn ct_bytes_iter(&self) -> impl Iterator<Item = u8> + '_ {
+        self.0.as_bytes().chunks(2).map(|pair| ct_decode_byte(pair[0], pair[1]))
+    }
+}
+
+/// Decode a pair of ASCII hex characters which are already known to be valid into a byte, without
+/// branching on the characters. A digit has bit 0x40 clear and its value in the low four bits,
+/// while a letter of either case has bit 0x40 set and its value less 9 in the low four bits.
+fn ct_decode_nibble(c: u8) -> u8 {
+    (c & 0x0f) + 9 * (c >> 6)
+}
+
+/// Decode a pair of ASCII hex characters which are already known to be valid into a byte, without
+/// branching on the characters. See `ct_decode_nibble`.
+fn ct_decode_byte(upper: u8, lower: u8) -> u8 {
+    (ct_decode_nibble(upper) << 4) | ct_decode_nibble(lower)
+}
+
+/// Decode a pair of ASCII hex characters which are already known to be valid into a byte.
+fn decode_byte(upper: u8, lower: u8) -> u8 {
+    let upper = hexchar_to_nibble(&(upper as char)).expect("There should never be an invalid hexchar here");
+    let lower = hexchar_to_nibble(&(lower as char)).expect("There should never be an invalid hexchar here");
+    (upper << 4) | lower
+}
+
+/// Append the hex characters of every byte in `v` to `hex`. With the `simd` feature on x86_64,
+/// whole 16 byte blocks are encoded with SSE2, and only the remaining tail goes through the scalar
+/// encoder.
+fn encode_into(v: &[u8], hex: &mut String) {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    let v = simd::encode_blocks_into(v, hex);
+    encode_scalar_into(v, hex);
+}
+
+/// Append the hex characters of every byte in `v` to `hex`, one byte at a time
+fn encode_scalar_into(v: &[u8], hex: &mut String) {
+    for b in v {
+        let [upper, lower] = u8_to_hex_bytes(*b);
+        hex.push(upper as char);
+        hex.push(lower as char);
+    }
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod simd {
+    use alloc::string::String;
+    use core::arch::x86_64::*;
+
+    /// Append the hex characters of as many whole 16 byte blocks of `v` as possible to `hex`,
+    /// returning the tail which is too short to fill a block. SSE2 is part of the x86_64 baseline,
+    /// so no runtime detection is needed.
+    pub fn encode_blocks_into<'a>(v: &'a [u8], hex: &mut String) -> &'a [u8] {
+        let blocks = v.chunks_exact(16);
+        let tail = blocks.remainder();
+        for block in blocks {
+            let mut out = [0; 32];
+            encode_block(block, &mut out);
+            // SAFETY: encode_block only writes the ASCII characters 0-9 and a-f, so the String
+            // remains valid UTF-8
+            unsafe { hex.as_mut_vec().extend_from_slice(&out) };
+        }
+        tail
+    }
+
+    /// Encode exactly 16 bytes into 32 lower-case hex characters
+    fn encode_block(block: &[u8], out: &mut [u8; 32]) {
+        assert_eq!(block.len(), 16);
+        // SAFETY: both pointers cover 16 readable or writable bytes, and the unaligned load and
+        // store intrinsics have no alignment requirement. SSE2 is always available on x86_64.
+        unsafe {
+            let input = _mm_loadu_si128(block.as_ptr() as *const __m128i);
+            let mask = _mm_set1_epi8(0x0f);
+            let upper = nibbles_to_ascii(_mm_and_si128(_mm_srli_epi16(input, 4), mask));
+            let lower = nibbles_to_ascii(_mm_and_si128(input, mask));
+            _mm_storeu_si128(out.as_mut_ptr() as *mut __m128i, _mm_unpacklo_epi8(upper, lower));
+            _mm_storeu_si128(out.as_mut_ptr().add(16) as *mut __m128i, _mm_unpackhi_epi8(upper, lower));
+        }
+    }
+
+    /// Convert each lane, which holds a nibble, to its hex character. Lanes above 9 are shifted
+    /// past the characters between '9' and 'a'.
+    ///
+    /// This is only unsafe because the intrinsics are; SSE2 is always available on x86_64.
+    #[inline]
+    unsafe fn nibbles_to_ascii(nibbles: __m128i) -> __m128i {
+        let letters = _mm_cmpgt_epi8(nibbles, _mm_set1_epi8(9));
+        let letter_offset = _mm_and_si128(letters, _mm_set1_epi8((b'a' - b'0' - 10) as i8));
+        _mm_add_epi8(_mm_add_epi8(nibbles, _mm_set1_epi8(b'0' as i8)), letter_offset)
+    }
+}
+
+/// The CRC-32 of every byte value, using the reflected IEEE polynomial, computed at compile time
+#[cfg(feature = "crc32")]
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Reverse the escaping which coreutils applies to the filename in a checksum line, returning None
+/// for an escape other than `\\`, `\n`, or `\r`
+fn unescape_sum_filename(filename: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(filename.len());
+    let mut chars = filename.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next()? {
+            '\\' => unescaped.push('\\'),
+            'n' => unescaped.push('\n'),
+            'r' => unescaped.push('\r'),
+            _ => return None,
+        }
+    }
+    Some(unescaped)
+}
+
+/// Convert a byte to its character if it is printable ASCII, including space, or to `placeholder`
+/// otherwise
+fn printable_or(b: u8, placeholder: char) -> char {
+    if b.is_ascii_graphic() || b == b' ' { b as char } else { placeholder }
+}
+
+/// Write hex characters to a formatter, honoring its padding flags and adding a `0x` prefix if the
+/// alternate flag is set
+fn pad_hex(f: &mut fmt::Formatter, hex: &str) -> fmt::Result {
+    if f.alternate() {
+        f.pad(&format!("0x{}", hex))
+    } else {
+        f.pad(hex)
+    }
+}
+
+/// Compare two byte sequences, which the caller has checked are the same length, without
+/// returning early at the first difference. The accumulator passes through `black_box` so that
+/// the compiler is discouraged from turning the fold back into an early return.
+fn ct_eq_bytes<A: Iterator<Item = u8>, B: Iterator<Item = u8>>(a: A, b: B) -> bool {
+    a.zip(b).fold(0, |acc, (x, y)| core::hint::black_box(acc | (x ^ y))) == 0
+}
+
+/// Implementing the FromStr trait will let it be combined better with other crates
+/// It refers the implementation to the existing `from_string` function.
+impl FromStr for HexString {
+    type Err = HexStringError;
+
+    fn from_str(s: &str) -> result::Result<Self, Self::Err> {
+        Self::from_string(s)
+    }
+}
+
+/// Dereferencing gives access to all of the `str` methods, which operate on the hex characters
+/// rather than on the decoded bytes.
+impl Deref for HexString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Comparing against a string is an exact, case-sensitive comparison of the hex characters, in the
+/// same way as comparing two HexStrings.
+impl PartialEq<str> for HexString {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for HexString {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<HexString> for str {
+    fn eq(&self, other: &HexString) -> bool {
+        self == other.0
+    }
+}
+
+impl PartialEq<HexString> for &str {
+    fn eq(&self, other: &HexString) -> bool {
+        *self == other.0
+    }
+}
+
+/// Adding works like `concat`, but reuses the buffer of the left hand side, in the same way as
+/// adding a `&str` to a `String`.
+impl<'a> Add<&'a HexString> for HexString {
+    type Output = HexString;
+
+    fn add(mut self, other: &'a HexString) -> HexString {
+        self.0.push_str(&other.0);
+        self
+    }
+}
+
+impl Not for &HexString {
+    type Output = HexString;
+
+    fn not(self) -> HexString {
+        HexString::not(self)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for HexString {
+    type Error = HexStringError;
+
+    fn try_from(s: &'a str) -> Result<Self> {
+        Self::from_string(s)
+    }
+}
+
+/// Unlike `from_string`, this takes ownership of a valid string rather than copying it.
+impl TryFrom<String> for HexString {
+    type Error = HexStringError;
+
+    fn try_from(s: String) -> Result<Self> {
+        validate(&s)?;
+        Ok(HexString(s))
+    }
+}
+
+impl<'a> From<&'a [u8]> for HexString {
+    fn from(v: &'a [u8]) -> Self {
+        Self::from_bytes(v)
+    }
+}
+
+impl From<Vec<u8>> for HexString {
+    fn from(v: Vec<u8>) -> Self {
+        Self::from_bytes(&v)
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for HexString {
+    fn from(v: [u8; N]) -> Self {
+        Self::from_bytes(&v)
+    }
+}
+
+impl<'a, const N: usize> From<&'a [u8; N]> for HexString {
+    fn from(v: &'a [u8; N]) -> Self {
+        Self::from_bytes(v)
+    }
+}
+
+/// Decoding into an array covers digests such as `[u8; 32]` for SHA-256, `[u8; 20]` for SHA-1,
+/// and `[u8; 16]` for MD5. This returns LengthMismatch if the HexString is not exactly `N` bytes.
+impl<const N: usize> TryFrom<HexString> for [u8; N] {
+    type Error = HexStringError;
+
+    fn try_from(hex: HexString) -> Result<Self> {
+        hex.to_array()
+    }
+}
+
+/// Collecting bytes encodes each one as it arrives, without first collecting them into a vector.
+impl FromIterator<u8> for HexString {
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut hex = String::with_capacity(iter.size_hint().0 * 2);
+        for b in iter {
+            let [upper, lower] = u8_to_hex_bytes(b);
+            hex.push(upper as char);
+            hex.push(lower as char);
+        }
+        HexString(hex)
+    }
+}
+
+/// Display writes the hex characters exactly as they would be returned by `as_str`. The alternate
+/// flag (`{:#}`) adds a `0x` prefix, and the width, fill, and alignment flags pad the result, so
+/// `format!("{:>#12}", hex)` right-aligns `0x` and the hex characters in twelve columns.
+impl fmt::Display for HexString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        pad_hex(f, &self.0)
+    }
+}
+
+/// LowerHex writes the hex characters with a-f in lower case, like `{:x}` for an integer,
+/// regardless of the case used to construct this HexString. The flags are handled as in `Display`.
+impl fmt::LowerHex for HexString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        pad_hex(f, &self.as_string_lower())
+    }
+}
+
+/// UpperHex writes the hex characters with A-F in upper case, like `{:X}` for an integer,
+/// regardless of the case used to construct this HexString. The `0x` prefix of the alternate flag
+/// stays in lower case, as it does for integers.
+impl fmt::UpperHex for HexString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        pad_hex(f, &self.as_string_upper())
+    }
+}
+
+impl AsRef<str> for HexString {
+    fn as_ref(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+/// This allows a map or set keyed by HexString to be queried with a `&str`. The hex characters are
+/// compared exactly, as in `Eq` and `Hash`, so the case of the query must match the key.
+impl Borrow<str> for HexString {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+/// This returns the ASCII bytes of the hex characters, *not* the decoded bytes. Use `as_bytes` to
+/// get the bytes that the hex string represents.
+impl AsRef<[u8]> for HexString {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+/// With the `serde` feature, a HexString serializes as its hex characters.
+#[cfg(feature = "serde")]
+impl serde::Serialize for HexString {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+/// With the `serde` feature, a HexString deserializes from a string, which is validated exactly as
+/// in `from_string`. Any validation errors are reported as serde errors.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for HexString {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> result::Result<Self, D::Error> {
+        struct HexStringVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for HexStringVisitor {
+            type Value = HexString;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an even-length string of hex characters")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, s: &str) -> result::Result<HexString, E> {
+                HexString::from_string(s).map_err(E::custom)
+            }
+
+            fn visit_string<E: serde::de::Error>(self, s: String) -> result::Result<HexString, E> {
+                HexString::try_from(s).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(HexStringVisitor)
+    }
+}
+
+/// With the `hex-compat` feature, a HexString can be decoded by code which is generic over the
+/// `hex` crate's `FromHex`. Since the bytes are decoded and encoded again, the result is always in
+/// lower case.
+#[cfg(feature = "hex-compat")]
+impl hex::FromHex for HexString {
+    type Error = hex::FromHexError;
+
+    fn from_hex<T: AsRef<[u8]>>(hex: T) -> result::Result<Self, Self::Error> {
+        hex::decode(hex).map(HexString::from)
+    }
+}
+
+/// With the `zeroize` feature, the hex characters can be cleared for HexStrings which hold secrets
+/// such as keys. This is best-effort: any copies of the characters which `String` left behind when
+/// reallocating, or which were made by `Clone`, are not cleared.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for HexString {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for HexString {}
+
+#[cfg(feature = "zeroize")]
+impl Drop for HexString {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+
+/// Bytes is an iterator which lazily decodes the bytes of a borrowed HexString. It is returned by
+/// `bytes_iter` and by iterating over a `&HexString`.
+#[derive(Clone, Debug)]
+pub struct Bytes<'a> {
+    pairs: slice::Chunks<'a, u8>,
+}
+
+impl<'a> Iterator for Bytes<'a> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        self.pairs.next().map(|pair| decode_byte(pair[0], pair[1]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.pairs.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for Bytes<'a> {
+    fn next_back(&mut self) -> Option<u8> {
+        self.pairs.next_back().map(|pair| decode_byte(pair[0], pair[1]))
+    }
+}
+
+impl<'a> ExactSizeIterator for Bytes<'a> {}
+
+/// IntoBytes is an iterator which lazily decodes the bytes of an owned HexString. It is returned by
+/// iterating over a `HexString`.
+#[derive(Clone, Debug)]
+pub struct IntoBytes {
+    hex: HexString,
+    position: usize,
+}
+
+impl Iterator for IntoBytes {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        let pair = self.hex.0.as_bytes().get(self.position..self.position + 2)?;
+        self.position += 2;
+        Some(decode_byte(pair[0], pair[1]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.hex.0.len() - self.position) / 2;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for IntoBytes {}
+
+impl IntoIterator for HexString {
+    type Item = u8;
+    type IntoIter = IntoBytes;
+
+    fn into_iter(self) -> IntoBytes {
+        IntoBytes { hex: self, position: 0 }
+    }
+}
+
+impl<'a> IntoIterator for &'a HexString {
+    type Item = u8;
+    type IntoIter = Bytes<'a>;
+
+    fn into_iter(self) -> Bytes<'a> {
+        self.bytes_iter()
+    }
+}
+
+
+/// HexStringBuilder accumulates bytes which are produced incrementally, appending their hex
+/// characters to a single growing String, and then finalizes them into a HexString. This avoids
+/// the allocations of repeatedly calling `from_bytes` and `concat`.
+#[derive(Clone, Debug, Default)]
+pub struct HexStringBuilder(String);
+
+impl HexStringBuilder {
+    /// Create an empty builder
+    pub fn new() -> HexStringBuilder {
+        HexStringBuilder(String::new())
+    }
+
+    /// Create an empty builder with space for `bytes` bytes before it needs to reallocate
+    pub fn with_capacity(bytes: usize) -> HexStringBuilder {
+        HexStringBuilder(String::with_capacity(bytes * 2))
+    }
+
+    /// Append a single byte
+    pub fn push_byte(&mut self, b: u8) -> &mut HexStringBuilder {
+        let [upper, lower] = u8_to_hex_bytes(b);
+        self.0.push(upper as char);
+        self.0.push(lower as char);
+        self
+    }
+
+    /// Append every byte in `bytes`
+    pub fn push_bytes(&mut self, bytes: &[u8]) -> &mut HexStringBuilder {
+        self.0.reserve(bytes.len() * 2);
+        encode_into(bytes, &mut self.0);
+        self
+    }
+
+    /// Finalize the bytes accumulated so far into a HexString
+    pub fn build(self) -> HexString {
+        HexString(self.0)
+    }
+}
+
+
+/// SecretHexString wraps a HexString which holds a secret, such as a key, so that it is not
+/// accidentally written to a log. Its `Debug` output never contains the hex characters, and it
+/// deliberately does not implement `Display`. Use `expose` when the value is actually needed.
+#[derive(Clone)]
+pub struct SecretHexString(HexString);
+
+impl SecretHexString {
+    /// Wrap a HexString as a secret
+    pub fn new(hex: HexString) -> SecretHexString {
+        SecretHexString(hex)
+    }
+
+    /// Return a reference to the secret HexString
+    pub fn expose(&self) -> &HexString {
+        &self.0
+    }
+
+    /// Unwrap the secret HexString
+    pub fn into_inner(self) -> HexString {
+        self.0
+    }
+}
+
+impl From<HexString> for SecretHexString {
+    fn from(hex: HexString) -> Self {
+        SecretHexString::new(hex)
+    }
+}
+
+impl fmt::Debug for SecretHexString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("SecretHexString(***redacted***)")
+    }
+}
+
+
+/// HexDecoder accepts hex characters through `io::Write`, in chunks of any size, and writes the
+/// decoded bytes to an inner writer. This allows a very large hex file to be decoded without
+/// loading it all into memory. A byte pair which is split across two writes is carried over to the
+/// next write.
+///
+/// Because a hex stream has no natural end, call `finish` once all of the input has been written
+/// to check that it was of even length and to get the inner writer back.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct HexDecoder<W: io::Write> {
+    inner: W,
+    pending: Option<u8>,
+    position: usize,
+}
+
+#[cfg(feature = "std")]
+impl<W: io::Write> HexDecoder<W> {
+    /// Create a decoder which writes decoded bytes to `inner`
+    pub fn new(inner: W) -> HexDecoder<W> {
+        HexDecoder { inner, pending: None, position: 0 }
+    }
+
+    /// Flush the decoder and return the inner writer.
+    ///
+    /// This will return an `InvalidData` error wrapping InvalidStringLength if an odd number of
+    /// hex characters were written in total.
+    pub fn finish(mut self) -> io::Result<W> {
+        if self.pending.is_some() {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, HexStringError::InvalidStringLength));
+        }
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+/// Each chunk is validated before anything is written to the inner writer, so a chunk containing
+/// an invalid character is rejected as a whole with an `InvalidData` error wrapping
+/// InvalidCharacterAt. The index counts every byte accepted by earlier writes. Because the input
+/// is handled as raw bytes, a non-ASCII character is reported as its first byte.
+#[cfg(feature = "std")]
+impl<W: io::Write> io::Write for HexDecoder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let Some(i) = buf.iter().position(|b| !b.is_ascii_hexdigit()) {
+            let err = HexStringError::InvalidCharacterAt { ch: buf[i] as char, index: self.position + i };
+            return Err(io::Error::new(io::ErrorKind::InvalidData, err));
+        }
+
+        let mut decoded = Vec::with_capacity(buf.len() / 2 + 1);
+        let mut pending = self.pending;
+        for b in buf {
+            match pending.take() {
+                Some(upper) => decoded.push(decode_byte(upper, *b)),
+                None => pending = Some(*b),
+            }
+        }
+
+        self.inner.write_all(&decoded)?;
+        self.pending = pending;
+        self.position += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+
+/// HexEncoder accepts raw bytes through `io::Write` and writes two lower-case hex characters per
+/// byte to an inner writer, so that the output of a hash computation or a file read can be piped
+/// straight to hex output without buffering all of it.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct HexEncoder<W: io::Write> {
+    inner: W,
+}
+
+#[cfg(feature = "std")]
+impl<W: io::Write> HexEncoder<W> {
+    /// Create an encoder which writes hex characters to `inner`
+    pub fn new(inner: W) -> HexEncoder<W> {
+        HexEncoder { inner }
+    }
+
+    /// Flush the encoder and return the inner writer
+    pub fn finish(mut self) -> io::Result<W> {
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+/// Every write encodes the whole buffer and passes all of it to the inner writer with
+/// `write_all`, so a short write by the inner writer never splits a byte's pair of characters.
+#[cfg(feature = "std")]
+impl<W: io::Write> io::Write for HexEncoder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut encoded = String::with_capacity(buf.len() * 2);
+        encode_into(buf, &mut encoded);
+
+        self.inner.write_all(encoded.as_bytes())?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    fn byte_repr() -> Vec<u8> { vec![203, 187, 198, 225, 155, 230, 62, 252, 221, 120, 50, 125, 45, 248, 80, 217, 35, 117, 175, 106, 3, 147, 79, 53, 228, 123, 208, 45, 27, 73, 108, 12] }
+    fn string_repr() -> String { String::from("cbbbc6e19be63efcdd78327d2df850d92375af6a03934f35e47bd02d1b496c0c") }
+
+    #[test]
+    fn it_converts_hexchars_to_nibbles_with_arithmetic() {
+        for c in (0u8..=255).map(|b| b as char).chain(vec!['é', '🦀']) {
+            let expected = c.to_digit(16).map(|d| d as u8);
+            assert_eq!(const_hexchar_to_nibble(c), expected, "converting {:?}", c);
+            match hexchar_to_nibble(&c) {
+                Ok(n) => assert_eq!(Some(n), expected),
+                Err(HexStringError::InvalidCharacter(e)) => { assert_eq!(e, c); assert_eq!(expected, None) },
+                Err(err) => panic!("unexpected error for {:?}: {:?}", c, err),
+            }
+        }
+
+        const TEN: Option<u8> = const_hexchar_to_nibble('A');
+        assert_eq!(TEN, Some(10));
+    }
+
+    #[test]
+    fn it_converts_nibbles_to_hexchars_with_arithmetic() {
+        for b in 0u8..=255 {
+            let expected = std::char::from_digit(b as u32, 16).filter(|_| b < 16);
+            assert_eq!(const_nibble_to_hexchar(b), expected, "converting {}", b);
+            match nibble_to_hexchar(&b) {
+                Ok(c) => assert_eq!(Some(c), expected),
+                Err(HexStringError::InvalidNibble(e)) => { assert_eq!(e, b); assert_eq!(expected, None) },
+                Err(err) => panic!("unexpected error for {}: {:?}", b, err),
+            }
+        }
+
+        const F: Option<char> = const_nibble_to_hexchar(15);
+        assert_eq!(F, Some('f'));
+    }
+
+    #[test]
+    fn it_rejects_nibbles_out_of_range() {
+        match Nibble::new(MAX_NIBBLE + 1) {
+            Err(HexStringError::InvalidNibble(16)) => (),
+            other => panic!("did not reject 16: {:?}", other),
+        }
+        match Nibble::from_char('g') {
+            Err(HexStringError::InvalidCharacter('g')) => (),
+            other => panic!("did not reject 'g': {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_round_trips_every_nibble() {
+        for value in 0..=MAX_NIBBLE {
+            let nibble = Nibble::new(value).unwrap();
+            assert_eq!(nibble.value(), value);
+            assert_eq!(u8::from(nibble), value);
+            assert_eq!(Nibble::from_char(nibble.to_char()).unwrap(), nibble);
+            assert_eq!(Nibble::from_char(nibble.to_char().to_ascii_uppercase()).unwrap(), nibble);
+        }
+    }
+
+    #[test]
+    fn it_converts_bytes_to_string() {
+        let res = HexString::from_bytes(&byte_repr());
+        assert_eq!(*res.as_string(), string_repr());
+    }
+
+    #[test]
+    fn it_converts_bytes_to_str_slice() {
+        let res = HexString::from_bytes(&byte_repr());
+        assert_eq!(res.as_str(), string_repr());
+    }
+
+    #[test]
+    fn it_borrows_the_hex_characters_as_a_cow() {
+        let hex_s = HexString::from_bytes(&byte_repr());
+        match hex_s.as_cow() {
+            Cow::Borrowed(s) => assert_eq!(s, string_repr()),
+            Cow::Owned(s) => panic!("cloned the hex characters: {}", s),
+        }
+    }
+
+    #[test]
+    fn it_converts_into_the_owned_string() {
+        let hex_s = HexString::from_string(&string_repr().to_uppercase()).unwrap();
+        let s: String = hex_s.into_string();
+        assert_eq!(s, string_repr().to_uppercase());
+        assert_eq!(HexString::empty().into_string(), "");
+    }
+
+    #[test]
+    fn it_converts_into_the_decoded_bytes() {
+        let hex_s = HexString::from_string(&string_repr().to_uppercase()).unwrap();
+        assert_eq!(hex_s.clone().into_bytes(), hex_s.as_bytes());
+        assert_eq!(hex_s.into_bytes(), byte_repr());
+        assert_eq!(HexString::empty().into_bytes(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn it_encodes_without_reallocating() {
+        let long = (0..1000).map(|i| i as u8).collect::<Vec<u8>>();
+        for bytes in [byte_repr(), long] {
+            let mut hex = String::with_capacity(bytes.len() * 2);
+            let (ptr, capacity) = (hex.as_ptr(), hex.capacity());
+            encode_into(&bytes, &mut hex);
+            assert_eq!((hex.as_ptr(), hex.capacity()), (ptr, capacity));
+            assert!(HexString::from_bytes(&bytes).0.capacity() >= bytes.len() * 2);
+        }
+    }
+
+    #[test]
+    fn it_allocates_room_to_append() {
+        let hex_s = HexString::from_bytes_with_capacity(&byte_repr(), 128);
+        assert_eq!(hex_s, HexString::from_bytes(&byte_repr()));
+        assert!(hex_s.0.capacity() >= 128);
+
+        let (ptr, capacity) = (hex_s.0.as_ptr(), hex_s.0.capacity());
+        let appended = hex_s + &HexString::from_bytes(&byte_repr());
+        assert_eq!(appended, format!("{}{}", string_repr(), string_repr()).as_str());
+        assert_eq!((appended.0.as_ptr(), appended.0.capacity()), (ptr, capacity));
+
+        assert!(HexString::from_bytes_with_capacity(&byte_repr(), 0).0.capacity() >= 64);
+    }
+
+    #[test]
+    fn it_converts_string_to_bytes() {
+        match HexString::from_string(&string_repr()) {
+            Err(err) => panic!("error encoding from string: {:?}", err),
+            Ok(res) => assert_eq!(res.as_bytes(), byte_repr()),
+        }
+    }
+
+    #[test]
+    fn it_decodes_into_a_single_presized_vector() {
+        let long = HexString::from_bytes(&(0..1000).map(|i| i as u8).collect::<Vec<u8>>());
+        for hex_s in [HexString::from_string(&string_repr()).unwrap(), long] {
+            let bytes = hex_s.as_bytes();
+            assert!(bytes.capacity() >= bytes.len());
+            // a vector which grew while decoding would not have the capacity of one sized up front
+            assert_eq!(bytes.capacity(), Vec::<u8>::with_capacity(bytes.len()).capacity());
+        }
+    }
+
+    #[test]
+    fn it_handles_the_empty_hex_string() {
+        let from_string = HexString::from_string("").unwrap();
+        let from_bytes = HexString::from_bytes(&[]);
+        for hex_s in [HexString::empty(), from_string, from_bytes] {
+            assert_eq!(hex_s, HexString::empty());
+            assert_eq!(hex_s.len(), 0);
+            assert_eq!(hex_s.char_len(), 0);
+            assert!(hex_s.is_empty());
+            assert_eq!(hex_s.as_bytes(), Vec::<u8>::new());
+            assert_eq!(hex_s.as_string(), "");
+            assert_eq!(hex_s.to_string(), "");
+        }
+    }
+
+    #[test]
+    fn it_encodes_bytes_in_either_case() {
+        let lower = HexString::from_bytes_cased(&byte_repr(), HexCase::Lower);
+        let upper = HexString::from_bytes_cased(&byte_repr(), HexCase::Upper);
+        assert_eq!(lower, HexString::from_bytes(&byte_repr()));
+        assert_eq!(upper.as_str(), string_repr().to_uppercase());
+        assert_eq!(lower.as_bytes(), upper.as_bytes());
+        assert_eq!(upper.as_bytes(), byte_repr());
+    }
+
+    #[test]
+    fn it_formats_in_either_case() {
+        let upper = HexString::from_bytes_cased(&byte_repr(), HexCase::Upper);
+        assert_eq!(upper.as_string_cased(HexCase::Lower), string_repr());
+        assert_eq!(upper.as_string_cased(HexCase::Upper), string_repr().to_uppercase());
+        assert_eq!(HexString::from_bytes(&byte_repr()).as_string_cased(HexCase::Upper), string_repr().to_uppercase());
+    }
+
+    #[test]
+    fn it_defaults_to_the_empty_hex_string() {
+        #[derive(Default)]
+        struct Manifest {
+            digest: HexString,
+        }
+
+        assert!(HexString::default().is_empty());
+        assert_eq!(HexString::default().as_bytes(), Vec::<u8>::new());
+        assert_eq!(Manifest::default().digest, HexString::empty());
+    }
+
+    #[test]
+    fn it_writes_hex_into_a_fmt_writer() {
+        let mut out = String::from("digest: ");
+        write_hex(&mut out, &byte_repr()).unwrap();
+        assert_eq!(out, format!("digest: {}", HexString::from_bytes(&byte_repr()).as_string()));
+
+        let long = (0..100).collect::<Vec<u8>>();
+        let mut out = String::new();
+        write_hex(&mut out, &long).unwrap();
+        assert_eq!(out, HexString::from_bytes(&long).as_string());
+
+        let mut out = String::new();
+        write_hex(&mut out, &[]).unwrap();
+        assert_eq!(out, "");
+    }
+
+    #[test]
+    fn it_validates_long_strings() {
+        let long_s = string_repr().repeat(1000);
+        let res = HexString::from_string(&long_s).expect("a long string of valid hex should be parsable");
+        assert_eq!(res.as_bytes(), byte_repr().repeat(1000));
+
+        let invalid_s = format!("{}g0", long_s);
+        match HexString::from_string(&invalid_s) {
+            Err(HexStringError::InvalidCharacterAt { ch: 'g', index: 64000 }) => (),
+            other => panic!("did not reject a 'g' at the end of a long string: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_reports_the_index_of_an_invalid_character() {
+        let mut invalid_s = string_repr();
+        invalid_s.replace_range(37..38, "z");
+        match HexString::from_string(&invalid_s) {
+            Err(HexStringError::InvalidCharacterAt { ch: 'z', index: 37 }) => (),
+            other => panic!("did not report the index of the 'z': {:?}", other),
+        }
+
+        match HexString::from_separated("de:ad:bz", ':') {
+            Err(HexStringError::InvalidCharacterAt { ch: 'z', index: 7 }) => (),
+            other => panic!("did not report the index of the 'z' including separators: {:?}", other),
+        }
+
+        match hexchar_to_nibble(&'z') {
+            Err(HexStringError::InvalidCharacter('z')) => (),
+            other => panic!("hexchar_to_nibble should not report an index: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_accepts_strings_within_a_length_limit() {
+        assert_eq!(HexString::from_string_bounded(&string_repr(), 32).unwrap(), *string_repr());
+        assert_eq!(HexString::from_string_bounded(&string_repr(), 64).unwrap(), *string_repr());
+        assert!(HexString::from_string_bounded("", 0).unwrap().is_empty());
+        assert!(HexString::from_string_bounded("cbb", 2).is_err());
+    }
+
+    #[test]
+    fn it_rejects_strings_over_a_length_limit() {
+        match HexString::from_string_bounded(&string_repr(), 31) {
+            Err(HexStringError::TooLong(31, 32)) => (),
+            other => panic!("did not reject a 32 byte string: {:?}", other),
+        }
+        match HexString::from_string_bounded("zz0", 1) {
+            Err(HexStringError::TooLong(1, 2)) => (),
+            other => panic!("did not check the length first: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_converts_errors_into_boxed_errors() {
+        fn parse(s: &str) -> result::Result<HexString, alloc::boxed::Box<dyn core::error::Error + Send + Sync>> {
+            Ok(HexString::from_string(s)?)
+        }
+
+        assert_eq!(parse("cbbb").unwrap(), "cbbb");
+        let err = parse("cbzb").unwrap_err();
+        assert_eq!(err.to_string(), "Encountered invalid character 'z' at index 2");
+        match err.downcast::<HexStringError>().map(|err| *err) {
+            Ok(HexStringError::InvalidCharacterAt { ch: 'z', index: 2 }) => (),
+            other => panic!("did not preserve the original error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_checks_validity_without_constructing() {
+        assert!(is_valid_hex(&string_repr()));
+        assert!(is_valid_hex(&string_repr().to_uppercase()));
+        assert!(is_valid_hex(""));
+        assert!(!is_valid_hex("abc"));
+        assert!(!is_valid_hex("abcz"));
+        assert!(!is_valid_hex("ab\u{e9}"));
+    }
+
+    #[test]
+    fn it_validates_without_constructing() {
+        assert!(validate(&string_repr()).is_ok());
+        assert!(validate("").is_ok());
+        match validate("abc") {
+            Err(HexStringError::InvalidStringLength) => (),
+            other => panic!("did not reject an odd length: {:?}", other),
+        }
+        match validate("abzd") {
+            Err(HexStringError::InvalidCharacterAt { ch: 'z', index: 2 }) => (),
+            other => panic!("did not reject an invalid character: {:?}", other),
+        }
+
+        for input in ["abc", "abzd", "zbc", "ab cd", ""] {
+            let from_string = HexString::from_string(input).map(|_| ());
+            assert_eq!(format!("{:?}", validate(input)), format!("{:?}", from_string));
+        }
+    }
+
+    #[test]
+    fn it_finds_every_invalid_character() {
+        assert_eq!(find_invalid("zbcdx0ab-1"), [(0, 'z'), (4, 'x'), (8, '-')]);
+        assert_eq!(find_invalid("ab\u{e9}g"), [(2, '\u{e9}'), (4, 'g')]);
+        assert!(find_invalid(&string_repr()).is_empty());
+        assert!(find_invalid("abc").is_empty());
+    }
+
+    #[test]
+    fn it_parses_strings_of_an_expected_length() {
+        assert_eq!(HexString::from_string_expecting(&string_repr(), 32).unwrap(), *string_repr());
+        assert!(HexString::from_string_expecting("", 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_rejects_strings_of_an_unexpected_length() {
+        match HexString::from_string_expecting(&string_repr()[..62], 32) {
+            Err(HexStringError::LengthMismatch(32, 31)) => (),
+            other => panic!("did not reject a short string: {:?}", other),
+        }
+        match HexString::from_string_expecting(&format!("{}00", string_repr()), 32) {
+            Err(HexStringError::LengthMismatch(32, 33)) => (),
+            other => panic!("did not reject a long string: {:?}", other),
+        }
+        match HexString::from_string_expecting("abc", 2) {
+            Err(HexStringError::InvalidStringLength) => (),
+            other => panic!("did not reject an odd length: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_parses_ascii_hex_bytes() {
+        assert_eq!(HexString::from_ascii_hex(string_repr().as_bytes()).unwrap(), *string_repr());
+        assert_eq!(HexString::from_ascii_hex(b"ABcd").unwrap(), "ABcd");
+        assert!(HexString::from_ascii_hex(b"").unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_rejects_invalid_ascii_hex_bytes() {
+        match HexString::from_ascii_hex(b"ab\ncd") {
+            Err(HexStringError::InvalidCharacterAt { ch: '\n', index: 2 }) => (),
+            other => panic!("did not reject a newline: {:?}", other),
+        }
+        match HexString::from_ascii_hex(&[b'a', 0xff]) {
+            Err(HexStringError::InvalidCharacterAt { ch: '\u{ff}', index: 1 }) => (),
+            other => panic!("did not reject a non-ASCII byte: {:?}", other),
+        }
+        match HexString::from_ascii_hex(b"abc") {
+            Err(HexStringError::InvalidStringLength) => (),
+            other => panic!("did not reject an odd length: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_trims_surrounding_whitespace() {
+        assert_eq!(HexString::from_string_trimmed(&format!("{}\n", string_repr())).unwrap(), *string_repr());
+        assert_eq!(HexString::from_string_trimmed(&format!("{}\r\n", string_repr())).unwrap(), *string_repr());
+        assert_eq!(HexString::from_string_trimmed("  abcd \t").unwrap(), "abcd");
+        assert!(HexString::from_string_trimmed("\n").unwrap().is_empty());
+        assert!(HexString::from_string("abcd\n").is_err());
+    }
+
+    #[test]
+    fn it_rejects_whitespace_inside_a_trimmed_string() {
+        match HexString::from_string_trimmed("  ab cd\n") {
+            Err(HexStringError::InvalidCharacterAt { ch: ' ', index: 4 }) => (),
+            other => panic!("did not reject inner whitespace: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_parses_a_checksum_line() {
+        let (digest, filename) = HexString::from_sum_line(&format!("{}  filename.txt", string_repr())).unwrap();
+        assert_eq!(digest, *string_repr());
+        assert_eq!(filename, "filename.txt");
+
+        let (digest, filename) = HexString::from_sum_line(&format!("{} *image.iso\n", string_repr())).unwrap();
+        assert_eq!(digest, *string_repr());
+        assert_eq!(filename, "image.iso");
+
+        let (_, filename) = HexString::from_sum_line("abcd  two  spaces.txt\r\n").unwrap();
+        assert_eq!(filename, "two  spaces.txt");
+    }
+
+    #[test]
+    fn it_parses_a_checksum_line_with_an_escaped_filename() {
+        let (digest, filename) = HexString::from_sum_line(&format!("\\{}  name\\nwith-newline\n", string_repr())).unwrap();
+        assert_eq!(digest, *string_repr());
+        assert_eq!(filename, "name\nwith-newline");
+
+        let (_, filename) = HexString::from_sum_line("\\abcd *back\\\\slash\\r.txt").unwrap();
+        assert_eq!(filename, "back\\slash\r.txt");
+
+        for line in ["\\abcd  bad\\escape", "\\abcd  trailing\\"] {
+            match HexString::from_sum_line(line) {
+                Err(HexStringError::MalformedSumLine(l)) => assert_eq!(l, line),
+                other => panic!("did not reject {:?}: {:?}", line, other),
+            }
+        }
+        match HexString::from_sum_line("\\abzd  filename.txt") {
+            Err(HexStringError::InvalidCharacterAt { ch: 'z', index: 3 }) => (),
+            other => panic!("did not reject an invalid escaped digest: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_rejects_malformed_checksum_lines() {
+        for line in ["abcd", "abcd filename.txt", "abcd  ", "  filename.txt", "abcd -filename.txt"] {
+            match HexString::from_sum_line(line) {
+                Err(HexStringError::MalformedSumLine(l)) => assert_eq!(l, line),
+                other => panic!("did not reject {:?}: {:?}", line, other),
+            }
+        }
+        match HexString::from_sum_line("abzd  filename.txt") {
+            Err(HexStringError::InvalidCharacterAt { ch: 'z', index: 2 }) => (),
+            other => panic!("did not reject an invalid digest: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_rejects_invalid_strings() {
+        match HexString::from_string("abcdefg") {
+            Err(_err) => (),
+            Ok(_) => panic!("did not reject a 'g' in the string"),
+        }
+    }
+
+    #[test]
+    fn it_reports_multibyte_characters_as_invalid_characters() {
+        match HexString::from_string("é0") {
+            Err(HexStringError::InvalidCharacterAt { ch: 'é', index: 0 }) => (),
+            other => panic!("did not report the accented character: {:?}", other),
+        }
+
+        match HexString::from_string("ab🦀") {
+            Err(HexStringError::InvalidCharacterAt { ch: '🦀', index: 2 }) => (),
+            other => panic!("did not report the emoji: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_strips_an_optional_prefix() {
+        let prefixed = HexString::from_string_prefixed(&format!("0x{}", string_repr())).unwrap();
+        assert_eq!(prefixed.as_bytes(), byte_repr());
+
+        let upper_prefixed = HexString::from_string_prefixed("0XABcd").unwrap();
+        assert_eq!(upper_prefixed.as_str(), "ABcd");
+
+        let unprefixed = HexString::from_string_prefixed(&string_repr()).unwrap();
+        assert_eq!(unprefixed.as_bytes(), byte_repr());
+    }
+
+    #[test]
+    fn it_accepts_a_bare_prefix_as_empty() {
+        let empty = HexString::from_string_prefixed("0x").unwrap();
+        assert_eq!(empty.as_str(), "");
+        assert_eq!(empty.as_bytes(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn it_rejects_invalid_characters_after_the_prefix() {
+        match HexString::from_string_prefixed("0xg0") {
+            Err(HexStringError::InvalidCharacterAt { ch: 'g', index: 2 }) => (),
+            other => panic!("did not reject a 'g' after the prefix: {:?}", other),
+        }
+
+        match HexString::from_string("0x00") {
+            Err(HexStringError::InvalidCharacterAt { ch: 'x', index: 1 }) => (),
+            other => panic!("from_string should not accept a prefix: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_strips_whitespace_when_lenient() {
+        let input = "cbbbc6e1 9be63efc\n\tdd78327d 2df850d9\r\n  2375af6a03934f35\ne47bd02d1b496c0c\n";
+        let hex_s = HexString::from_string_lenient(input).unwrap();
+        assert_eq!(hex_s.as_string(), string_repr());
+        assert!(HexString::from_string_lenient(" \n ").unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_rejects_odd_lengths_after_stripping_whitespace() {
+        match HexString::from_string_lenient("ab cd e\n") {
+            Err(HexStringError::InvalidStringLength) => (),
+            other => panic!("did not reject an odd number of hex characters: {:?}", other),
+        }
+
+        match HexString::from_string_lenient("ab cd:ef") {
+            Err(HexStringError::InvalidCharacterAt { ch: ':', index: 5 }) => (),
+            other => panic!("did not reject a ':' in the string: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_pads_odd_length_strings() {
+        let hex_s = HexString::from_string_padded("f").unwrap();
+        assert_eq!(hex_s.as_str(), "0f");
+        assert_eq!(hex_s.as_bytes(), vec![0x0f]);
+
+        assert_eq!(HexString::from_string_padded("1f3").unwrap().as_bytes(), vec![0x01, 0xf3]);
+    }
+
+    #[test]
+    fn it_does_not_pad_even_length_strings() {
+        let hex_s = HexString::from_string_padded(&string_repr()).unwrap();
+        assert_eq!(hex_s.as_string(), string_repr());
+        assert_eq!(HexString::from_string_padded("").unwrap().as_str(), "");
+    }
+
+    #[test]
+    fn it_rejects_invalid_characters_when_padding() {
+        match HexString::from_string_padded("fg0") {
+            Err(HexStringError::InvalidCharacterAt { ch: 'g', index: 1 }) => (),
+            other => panic!("did not reject a 'g' in the string: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_parses_colon_separated_strings() {
+        let mac = HexString::from_separated("de:ad:BE:ef", ':').unwrap();
+        assert_eq!(mac.as_str(), "deadBEef");
+        assert_eq!(mac.as_bytes(), vec![0xde, 0xad, 0xbe, 0xef]);
+    }
+
+    #[test]
+    fn it_parses_space_separated_strings() {
+        let fingerprint = HexString::from_separated("AB CD EF", ' ').unwrap();
+        assert_eq!(fingerprint.as_bytes(), vec![0xab, 0xcd, 0xef]);
+
+        let empty = HexString::from_separated("", ' ').unwrap();
+        assert_eq!(empty.as_str(), "");
+    }
+
+    #[test]
+    fn it_rejects_malformed_separated_groups() {
+        match HexString::from_separated("de:a:be:ef", ':') {
+            Err(HexStringError::InvalidGroup(group)) => assert_eq!(group, "a"),
+            other => panic!("did not reject a single character group: {:?}", other),
+        }
+
+        match HexString::from_separated("dead:beef", ':') {
+            Err(HexStringError::InvalidGroup(group)) => assert_eq!(group, "dead"),
+            other => panic!("did not reject a four character group: {:?}", other),
+        }
+
+        match HexString::from_separated("de::ad", ':') {
+            Err(HexStringError::InvalidGroup(group)) => assert_eq!(group, ""),
+            other => panic!("did not reject an empty group: {:?}", other),
+        }
+
+        match HexString::from_separated("de:ag", ':') {
+            Err(HexStringError::InvalidCharacterAt { ch: 'g', index: 4 }) => (),
+            other => panic!("did not reject a 'g' in a group: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_reports_its_length_in_bytes() {
+        let hex_s = HexString::from_string(&string_repr()).unwrap();
+        assert_eq!(hex_s.len(), 32);
+        assert_eq!(hex_s.char_len(), 64);
+        assert!(!hex_s.is_empty());
+    }
+
+    #[cfg(all(feature = "rand", feature = "std"))]
+    #[test]
+    fn it_generates_random_hex_strings() {
+        let first = HexString::random(16);
+        let second = HexString::random(16);
+        assert_eq!(first.char_len(), 32);
+        assert_eq!(second.char_len(), 32);
+        assert_ne!(first, second);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn it_generates_reproducible_hex_strings_from_a_given_rng() {
+        let first = HexString::random_with(&mut rand::rngs::mock::StepRng::new(1, 1), 16);
+        let second = HexString::random_with(&mut rand::rngs::mock::StepRng::new(1, 1), 16);
+        assert_eq!(first, second);
+        assert_eq!(first, "01000000000000000200000000000000");
+    }
+
+    #[test]
+    fn it_encodes_fixed_size_arrays() {
+        let digest = [0xcbu8, 0xbb, 0xc6, 0xe1];
+        assert_eq!(HexString::from_bytes_exact(&digest), "cbbbc6e1");
+    }
+
+    #[test]
+    fn it_checks_for_an_expected_length() {
+        let sha256 = HexString::from_bytes(&byte_repr());
+        assert_eq!(sha256.clone().expect_len(32).unwrap(), sha256);
+
+        match HexString::from_bytes(&[0; 20]).expect_len(32) {
+            Err(HexStringError::LengthMismatch(32, 20)) => (),
+            other => panic!("did not reject a 20 byte value: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_reports_when_it_is_empty() {
+        let hex_s = HexString::from_bytes(&[]);
+        assert_eq!(hex_s.len(), 0);
+        assert_eq!(hex_s.char_len(), 0);
+        assert!(hex_s.is_empty());
+    }
+
+    #[test]
+    fn it_iterates_over_decoded_bytes() {
+        let hex_s = HexString::from_string(&string_repr()).unwrap();
+        assert_eq!(hex_s.bytes_iter().collect::<Vec<u8>>(), hex_s.as_bytes());
+        assert_eq!(HexString::from_bytes(&[]).bytes_iter().count(), 0);
+    }
+
+    #[test]
+    fn it_iterates_over_bytes_in_reverse() {
+        let hex_s = HexString::from_string("0102ff").unwrap();
+        assert_eq!(hex_s.bytes_iter().rev().collect::<Vec<u8>>(), vec![0xff, 0x02, 0x01]);
+        assert_eq!(hex_s.bytes_iter().len(), 3);
+    }
+
+    #[test]
+    fn it_can_be_iterated_by_reference() {
+        let hex_s = HexString::from_bytes(&byte_repr());
+        assert_eq!((&hex_s).into_iter().collect::<Vec<u8>>(), hex_s.as_bytes());
+
+        let mut total = 0usize;
+        for b in &hex_s {
+            total += b as usize;
+        }
+        assert_eq!(total, byte_repr().iter().map(|b| *b as usize).sum::<usize>());
+    }
+
+    #[test]
+    fn it_can_be_iterated_by_value() {
+        let hex_s = HexString::from_bytes(&byte_repr());
+        let mut iter = hex_s.clone().into_iter();
+        assert_eq!(iter.len(), 32);
+        assert_eq!(iter.next(), Some(0xcb));
+        assert_eq!(iter.len(), 31);
+
+        assert_eq!(hex_s.into_iter().collect::<Vec<u8>>(), byte_repr());
+    }
+
+    #[test]
+    fn it_iterates_over_nibbles() {
+        let hex_s = HexString::from_string("a5").unwrap();
+        assert_eq!(hex_s.nibbles().collect::<Vec<u8>>(), vec![10, 5]);
+        assert_eq!(HexString::from_string("0F").unwrap().nibbles().collect::<Vec<u8>>(), vec![0, 15]);
+    }
+
+    #[test]
+    fn it_decodes_into_an_exactly_sized_buffer() {
+        let mut out = [0u8; 32];
+        let written = HexString::from_bytes(&byte_repr()).decode_into(&mut out).unwrap();
+        assert_eq!(written, 32);
+        assert_eq!(out.to_vec(), byte_repr());
+    }
+
+    #[test]
+    fn it_decodes_into_an_oversized_buffer() {
+        let mut out = [0xffu8; 6];
+        let written = HexString::from_string("cbbbc6e1").unwrap().decode_into(&mut out).unwrap();
+        assert_eq!(written, 4);
+        assert_eq!(out, [0xcb, 0xbb, 0xc6, 0xe1, 0xff, 0xff]);
+    }
+
+    #[test]
+    fn it_refuses_to_decode_into_a_small_buffer() {
+        let mut out = [0u8; 20];
+        match HexString::from_bytes(&byte_repr()).decode_into(&mut out) {
+            Err(HexStringError::BufferTooSmall(32, 20)) => (),
+            other => panic!("did not reject a 20 byte buffer: {:?}", other),
+        }
+        assert_eq!(out, [0u8; 20]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_writes_bytes_to_a_writer() {
+        let hex_s = HexString::from_bytes(&byte_repr());
+        let mut out = Vec::new();
+        hex_s.write_bytes_to(&mut out).unwrap();
+        assert_eq!(out, hex_s.as_bytes());
+
+        let long = HexString::from_bytes(&(0..1000).map(|i| i as u8).collect::<Vec<u8>>());
+        let mut out = Vec::new();
+        long.write_bytes_to(&mut out).unwrap();
+        assert_eq!(out, long.as_bytes());
+
+        let mut out = Vec::new();
+        HexString::from_bytes(&[]).write_bytes_to(&mut out).unwrap();
+        assert!(out.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_decodes_a_stream_split_inside_a_byte_pair() {
+        use std::io::Write;
+
+        let input = string_repr();
+        let chunks = input.as_bytes();
+        let mut decoder = HexDecoder::new(Vec::new());
+        decoder.write_all(&chunks[..7]).unwrap();
+        decoder.write_all(&chunks[7..8]).unwrap();
+        decoder.write_all(&chunks[8..33]).unwrap();
+        decoder.write_all(&chunks[33..]).unwrap();
+        let output = decoder.finish().unwrap();
+
+        assert_eq!(output, HexString::from_string(&input).unwrap().as_bytes());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_rejects_invalid_characters_mid_stream() {
+        use std::io::Write;
+
+        let mut decoder = HexDecoder::new(Vec::new());
+        decoder.write_all(b"cbb").unwrap();
+        let err = decoder.write_all(b"bg0").expect_err("did not reject a 'g' in the stream");
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        match err.get_ref().and_then(|e| e.downcast_ref::<HexStringError>()) {
+            Some(HexStringError::InvalidCharacterAt { ch: 'g', index: 4 }) => (),
+            other => panic!("unexpected inner error: {:?}", other),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_rejects_an_odd_length_stream_when_finished() {
+        use std::io::Write;
+
+        let mut decoder = HexDecoder::new(Vec::new());
+        decoder.write_all(b"cbb").unwrap();
+        decoder.write_all(b"bc").unwrap();
+        let err = decoder.finish().expect_err("did not reject an odd length stream");
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_encodes_a_stream_of_small_writes() {
+        use std::io::Write;
+
+        let input = byte_repr();
+        let mut encoder = HexEncoder::new(Vec::new());
+        for chunk in input.chunks(5) {
+            encoder.write_all(chunk).unwrap();
+        }
+        let output = encoder.finish().unwrap();
+
+        assert_eq!(String::from_utf8(output).unwrap(), HexString::from_bytes(&input).as_string());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_encodes_to_a_writer_which_accepts_partial_writes() {
+        use std::io::{self, Write};
+
+        struct OneByteWriter(Vec<u8>);
+
+        impl Write for OneByteWriter {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                match buf.first() {
+                    Some(b) => { self.0.push(*b); Ok(1) },
+                    None => Ok(0),
+                }
+            }
+
+            fn flush(&mut self) -> io::Result<()> { Ok(()) }
+        }
+
+        let mut encoder = HexEncoder::new(OneByteWriter(Vec::new()));
+        assert_eq!(encoder.write(&byte_repr()).unwrap(), 32);
+        let output = encoder.finish().unwrap();
+
+        assert_eq!(String::from_utf8(output.0).unwrap(), string_repr());
+    }
+
+    #[test]
+    fn it_round_trips_big_endian_integers() {
+        let hex_s = HexString::from_u64_be(0x0000000100000002);
+        assert_eq!(hex_s, "0000000100000002");
+        assert_eq!(hex_s.to_u64_be().unwrap(), 0x0000000100000002);
+
+        let wide = HexString::from_u128_be(0x0000000100000002);
+        assert_eq!(wide, "00000000000000000000000100000002");
+        assert_eq!(wide.to_u128_be().unwrap(), 0x0000000100000002);
+
+        assert_eq!(HexString::from_string("0102").unwrap().to_u64_be().unwrap(), 0x0102);
+    }
+
+    #[test]
+    fn it_round_trips_little_endian_integers() {
+        let hex_s = HexString::from_u64_le(0x0000000100000002);
+        assert_eq!(hex_s, "0200000001000000");
+        assert_eq!(hex_s.to_u64_le().unwrap(), 0x0000000100000002);
+
+        let wide = HexString::from_u128_le(0x0000000100000002);
+        assert_eq!(wide, "02000000010000000000000000000000");
+        assert_eq!(wide.to_u128_le().unwrap(), 0x0000000100000002);
+
+        assert_eq!(HexString::from_string("0102").unwrap().to_u64_le().unwrap(), 0x0201);
+    }
+
+    #[test]
+    fn it_rejects_integers_which_are_too_wide() {
+        match HexString::from_bytes(&[1; 9]).to_u64_be() {
+            Err(HexStringError::IntegerOverflow(9, 8)) => (),
+            other => panic!("did not reject a 9 byte u64: {:?}", other),
+        }
+        assert!(HexString::from_bytes(&byte_repr()).to_u128_le().is_err());
+    }
+
+    #[test]
+    fn it_trims_leading_zero_bytes() {
+        assert_eq!(HexString::from_string("0000abcd").unwrap().trim_leading_zero_bytes(), "abcd");
+        assert_eq!(HexString::from_string("000abcde").unwrap().trim_leading_zero_bytes(), "0abcde");
+        assert_eq!(HexString::from_string("ab0000").unwrap().trim_leading_zero_bytes(), "ab0000");
+        assert_eq!(HexString::from_string("00000000").unwrap().trim_leading_zero_bytes(), "00");
+        assert!(HexString::empty().trim_leading_zero_bytes().is_empty());
+    }
+
+    #[test]
+    fn it_pads_to_a_number_of_bytes() {
+        let hex_s = HexString::from_string("abcd").unwrap();
+        assert_eq!(hex_s.pad_left_bytes(4), "0000abcd");
+        assert_eq!(hex_s.pad_right_bytes(4), "abcd0000");
+        assert_eq!(hex_s.pad_left_bytes(4).as_bytes(), [0, 0, 0xab, 0xcd]);
+
+        assert_eq!(hex_s.pad_left_bytes(2), hex_s);
+        assert_eq!(hex_s.pad_right_bytes(1), hex_s);
+        assert_eq!(HexString::empty().pad_left_bytes(2), "0000");
+    }
+
+    #[test]
+    fn it_reverses_the_byte_order() {
+        assert_eq!(HexString::from_string("0102").unwrap().reverse_bytes(), "0201");
+        assert_eq!(HexString::from_string("abcd").unwrap().reverse_bytes(), "cdab");
+        assert!(HexString::from_bytes(&[]).reverse_bytes().is_empty());
+
+        let mut reversed = byte_repr();
+        reversed.reverse();
+        assert_eq!(HexString::from_bytes(&byte_repr()).reverse_bytes().as_bytes(), reversed);
+    }
+
+    #[test]
+    fn it_formats_groups_with_a_separator() {
+        let hex_s = HexString::from_string("deadbeef1234").unwrap();
+        assert_eq!(hex_s.to_grouped(1, ':'), "de:ad:be:ef:12:34");
+        assert_eq!(hex_s.to_grouped(2, ' '), "dead beef 1234");
+        assert_eq!(hex_s.to_grouped(4, ' '), "deadbeef 1234");
+        assert_eq!(hex_s.to_grouped(0, ' '), "deadbeef1234");
+        assert_eq!(HexString::from_bytes(&[]).to_grouped(1, ':'), "");
+
+        assert_eq!(HexString::from_separated(&hex_s.to_grouped(1, ':'), ':').unwrap(), hex_s);
+    }
+
+    #[test]
+    fn it_interprets_bytes_as_ascii() {
+        assert_eq!(HexString::from_string("68656c6c6f").unwrap().as_ascii_lossy(), "hello");
+        assert_eq!(HexString::from_string("68690a00ff7e20").unwrap().as_ascii_lossy(), "hi...~ ");
+        assert_eq!(HexString::from_string("68690a").unwrap().as_ascii_lossy_with('?'), "hi?");
+        assert_eq!(HexString::empty().as_ascii_lossy(), "");
+    }
+
+    #[test]
+    fn it_encodes_ascii_text() {
+        assert_eq!(HexString::from_ascii("hello").unwrap(), "68656c6c6f");
+        assert_eq!(HexString::from_ascii("hi\n").unwrap().as_ascii_lossy(), "hi.");
+        assert!(HexString::from_ascii("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_rejects_non_ascii_text() {
+        match HexString::from_ascii("caf\u{e9}") {
+            Err(HexStringError::InvalidCharacterAt { ch: '\u{e9}', index: 3 }) => (),
+            other => panic!("did not reject a non-ASCII character: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_formats_a_checksum_line() {
+        let digest = HexString::from_string(&string_repr().to_uppercase()).unwrap();
+        assert_eq!(digest.to_sum_line("filename.txt", false), format!("{}  filename.txt", string_repr()));
+        assert_eq!(digest.to_sum_line("image.iso", true), format!("{} *image.iso", string_repr()));
+
+        let (parsed, filename) = HexString::from_sum_line(&digest.to_sum_line("a b.txt", true)).unwrap();
+        assert_eq!(parsed, *string_repr());
+        assert_eq!(filename, "a b.txt");
+    }
+
+    #[test]
+    fn it_escapes_a_filename_in_a_checksum_line() {
+        let digest = HexString::from_string("abcd").unwrap();
+        assert_eq!(digest.to_sum_line("name\nwith-newline", false), "\\abcd  name\\nwith-newline");
+        assert_eq!(digest.to_sum_line("back\\slash\r.txt", true), "\\abcd *back\\\\slash\\r.txt");
+
+        let (parsed, filename) = HexString::from_sum_line(&digest.to_sum_line("a\\b\nc", false)).unwrap();
+        assert_eq!(parsed, "abcd");
+        assert_eq!(filename, "a\\b\nc");
+    }
+
+    #[test]
+    fn it_formats_a_hexdump() {
+        let hex_s = HexString::from_bytes(b"hello world, this is a hexdump\n");
+        assert_eq!(hex_s.hexdump(), concat!(
+            "00000000  68 65 6c 6c 6f 20 77 6f  72 6c 64 2c 20 74 68 69  |hello world, thi|\n",
+            "00000010  73 20 69 73 20 61 20 68  65 78 64 75 6d 70 0a     |s is a hexdump.|\n",
+        ));
+        assert_eq!(HexString::from_bytes(&[]).hexdump(), "");
+    }
+
+    #[test]
+    fn it_formats_a_hexdump_with_a_custom_width() {
+        let hex_s = HexString::from_bytes(&[0x00, 0x41, 0x42, 0x7f, 0x43]);
+        assert_eq!(hex_s.hexdump_with(4), concat!(
+            "00000000  00 41  42 7f  |.AB.|\n",
+            "00000004  43            |C|\n",
+        ));
+    }
+
+    #[test]
+    fn it_concatenates_two_hex_strings() {
+        let first = HexString::from_bytes(&byte_repr());
+        let second = HexString::from_bytes(&byte_repr().iter().rev().cloned().collect::<Vec<u8>>());
+        let joined = first.concat(&second);
+
+        assert_eq!(joined.char_len(), 128);
+        assert_eq!(joined.as_bytes(), [first.as_bytes(), second.as_bytes()].concat());
+        assert_eq!(first + &second, joined);
+    }
+
+    #[test]
+    fn it_repeats_the_bytes() {
+        let hex_s = HexString::from_string("ab").unwrap();
+        let repeated: HexString = hex_s.repeat(3);
+        assert_eq!(repeated, "ababab");
+        assert_eq!(HexString::from_string("deadbeef").unwrap().repeat(2).as_bytes(), [0xde, 0xad, 0xbe, 0xef, 0xde, 0xad, 0xbe, 0xef]);
+        assert!(hex_s.repeat(0).is_empty());
+    }
+
+    #[test]
+    fn it_slices_a_range_of_bytes() {
+        let hex_s = HexString::from_bytes(&byte_repr());
+        let prefix = hex_s.slice_bytes(0..4).unwrap();
+        assert_eq!(prefix.as_str(), "cbbbc6e1");
+        assert_eq!(prefix.as_bytes(), byte_repr()[0..4].to_vec());
+
+        assert_eq!(hex_s.slice_bytes(30..32).unwrap().as_bytes(), vec![0x6c, 0x0c]);
+        assert!(hex_s.slice_bytes(4..4).unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_rejects_out_of_range_slices() {
+        let hex_s = HexString::from_bytes(&byte_repr());
+        match hex_s.slice_bytes(30..33) {
+            Err(HexStringError::OutOfBounds(range, 32)) => assert_eq!(range, 30..33),
+            other => panic!("did not reject a range past the end: {:?}", other),
+        }
+
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = hex_s.slice_bytes(4..2);
+        assert!(reversed.is_err());
+    }
+
+    #[test]
+    fn it_splits_at_a_byte_boundary() {
+        let hex_s = HexString::from_bytes(&byte_repr());
+        let (head, tail) = hex_s.split_at_byte(16).unwrap();
+        assert_eq!(head.len(), 16);
+        assert_eq!(tail.len(), 16);
+        assert_eq!(head.as_bytes(), byte_repr()[..16].to_vec());
+        assert_eq!(tail.as_bytes(), byte_repr()[16..].to_vec());
+
+        let (head, tail) = hex_s.split_at_byte(32).unwrap();
+        assert_eq!(head, hex_s);
+        assert!(tail.is_empty());
+        assert!(hex_s.split_at_byte(0).unwrap().0.is_empty());
+    }
+
+    #[test]
+    fn it_rejects_splitting_past_the_end() {
+        match HexString::from_bytes(&byte_repr()).split_at_byte(33) {
+            Err(HexStringError::OutOfBounds(range, 32)) => assert_eq!(range, 0..33),
+            other => panic!("did not reject a split past the end: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_iterates_over_chunks_of_bytes() {
+        let hex_s = HexString::from_bytes(&byte_repr());
+        let chunks = hex_s.chunks_bytes(8).collect::<Vec<HexString>>();
+        assert_eq!(chunks.len(), 4);
+        assert!(chunks.iter().all(|chunk| chunk.len() == 8));
+        assert_eq!(chunks.iter().fold(HexString::from_bytes(&[]), |acc, chunk| acc.concat(chunk)), hex_s);
+
+        let uneven = hex_s.chunks_bytes(10).map(|chunk| chunk.len()).collect::<Vec<usize>>();
+        assert_eq!(uneven, [10, 10, 10, 2]);
+        assert_eq!(HexString::from_bytes(&[]).chunks_bytes(8).count(), 0);
+    }
+
+    #[test]
+    fn it_truncates_to_a_number_of_bits() {
+        let hex_s = HexString::from_string("abcdef").unwrap();
+        assert_eq!(hex_s.truncate_bits(12).unwrap(), "abc0");
+        assert_eq!(hex_s.truncate_bits(16).unwrap(), "abcd");
+        assert_eq!(hex_s.truncate_bits(1).unwrap(), "80");
+        assert_eq!(hex_s.truncate_bits(24).unwrap(), "abcdef");
+        assert!(hex_s.truncate_bits(0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_refuses_to_truncate_to_more_bits_than_are_available() {
+        match HexString::from_string("abcdef").unwrap().truncate_bits(25) {
+            Err(HexStringError::OutOfBounds(range, 24)) => assert_eq!(range, 0..25),
+            other => panic!("did not reject 25 bits: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_abbreviates_to_a_short_form() {
+        let hex_s = HexString::from_bytes(&byte_repr());
+        let short = hex_s.short(4);
+        assert_eq!(short.char_len(), 8);
+        assert_eq!(short, "cbbbc6e1");
+
+        assert_eq!(hex_s.short(100), hex_s);
+        assert!(hex_s.short(0).is_empty());
+    }
+
+    #[test]
+    fn it_xors_two_hex_strings() {
+        let a = HexString::from_string("0f0fff00").unwrap();
+        let b = HexString::from_string("ff00F0aa").unwrap();
+        assert_eq!(a.xor(&b).unwrap(), "f00f0faa");
+
+        let digest = HexString::from_bytes(&byte_repr());
+        assert_eq!(digest.xor(&digest).unwrap().as_bytes(), vec![0; 32]);
+    }
+
+    #[test]
+    fn it_refuses_to_xor_hex_strings_of_different_lengths() {
+        let a = HexString::from_string("0f0f").unwrap();
+        let b = HexString::from_string("ff00aa").unwrap();
+        match a.xor(&b) {
+            Err(HexStringError::LengthMismatch(2, 3)) => (),
+            other => panic!("did not reject a length mismatch: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_ands_and_ors_two_hex_strings() {
+        let a = HexString::from_string("0f0fff00").unwrap();
+        let b = HexString::from_string("ff00F0aa").unwrap();
+        assert_eq!(a.bitand(&b).unwrap(), "0f00f000");
+        assert_eq!(a.bitor(&b).unwrap(), "ff0fffaa");
+
+        let digest = HexString::from_bytes(&byte_repr());
+        assert_eq!(digest.bitand(&digest).unwrap(), digest);
+        assert_eq!(digest.bitor(&HexString::from_bytes(&[0; 32])).unwrap(), digest);
+    }
+
+    #[test]
+    fn it_refuses_to_and_or_or_hex_strings_of_different_lengths() {
+        let a = HexString::from_string("0f0f").unwrap();
+        let b = HexString::from_string("ff00aa").unwrap();
+        match a.bitand(&b) {
+            Err(HexStringError::LengthMismatch(2, 3)) => (),
+            other => panic!("did not reject a length mismatch: {:?}", other),
+        }
+        match b.bitor(&a) {
+            Err(HexStringError::LengthMismatch(3, 2)) => (),
+            other => panic!("did not reject a length mismatch: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_inverts_every_bit() {
+        let hex_s = HexString::from_string("00ff").unwrap();
+        assert_eq!(hex_s.not(), "ff00");
+        assert_eq!(!&hex_s, "ff00");
+        assert_eq!(HexString::from_string("0F5a").unwrap().not(), "f0a5");
+
+        let digest = HexString::from_bytes(&byte_repr());
+        assert_eq!(!&!&digest, digest);
+        assert!(HexString::empty().not().is_empty());
+    }
+
+    #[test]
+    fn it_shifts_bits_left_within_the_width() {
+        let hex_s = HexString::from_string("0001").unwrap();
+        assert_eq!(hex_s.shl(8), "0100");
+        assert_eq!(hex_s.shl(4), "0010");
+        assert_eq!(hex_s.shl(15), "8000");
+        assert_eq!(hex_s.shl(16), "0000");
+        assert_eq!(hex_s.shl(0), hex_s);
+        assert_eq!(HexString::from_string("81ff").unwrap().shl(1), "03fe");
+    }
+
+    #[test]
+    fn it_shifts_bits_right_within_the_width() {
+        let hex_s = HexString::from_string("0100").unwrap();
+        assert_eq!(hex_s.shr(4), "0010");
+        assert_eq!(hex_s.shr(8), "0001");
+        assert_eq!(hex_s.shr(9), "0000");
+        assert_eq!(HexString::from_string("0001").unwrap().shr(4), "0000");
+        assert_eq!(HexString::from_string("ff81").unwrap().shr(1), "7fc0");
+        assert_eq!(HexString::from_string("ff81").unwrap().shr(100), "0000");
+        assert!(HexString::empty().shr(3).is_empty());
+    }
+
+    #[test]
+    fn it_folds_down_to_a_fixed_size() {
+        let hex_s = HexString::from_bytes(&byte_repr());
+        // the four 8 byte chunks of byte_repr, XORed together by hand
+        assert_eq!(hex_s.fold_xor(8).unwrap(), "d1cd8bdbaec44d1c");
+        assert_eq!(hex_s.fold_xor(32).unwrap(), hex_s);
+        assert_eq!(HexString::from_string("0102f0f0").unwrap().fold_xor(2).unwrap(), "f1f2");
+        assert_eq!(HexString::from_string("0102f0f0").unwrap().fold_xor(1).unwrap(), "03");
+        assert_eq!(HexString::empty().fold_xor(4).unwrap(), "00000000");
+    }
+
+    #[test]
+    fn it_refuses_to_fold_into_a_size_which_does_not_divide_the_length() {
+        let hex_s = HexString::from_bytes(&byte_repr());
+        match hex_s.fold_xor(5) {
+            Err(HexStringError::NotMultipleOf(32, 5)) => (),
+            other => panic!("did not reject folding into 5 bytes: {:?}", other),
+        }
+        assert!(hex_s.fold_xor(0).is_err());
+    }
+
+    #[test]
+    fn it_compares_while_ignoring_case() {
+        let lower = HexString::from_string(&string_repr()).unwrap();
+        let mixed = HexString::from_string("cbbbC6E19BE63efcdd78327d2df850d92375af6a03934f35e47bd02d1b496C0C").unwrap();
+
+        assert!(lower.eq_ignore_case(&mixed));
+        assert!(mixed.eq_ignore_case(&lower));
+        assert!(lower != mixed);
+        assert!(!lower.eq_ignore_case(&HexString::from_string("cbbb").unwrap()));
+    }
+
+    #[test]
+    fn it_guesses_the_algorithm_from_the_length() {
+        let guess = |bytes: usize| HexString::from_bytes(&vec![0; bytes]).guess_algorithm();
+        assert_eq!(guess(16), Some("MD5"));
+        assert_eq!(guess(20), Some("SHA-1"));
+        assert_eq!(guess(28), Some("SHA-224"));
+        assert_eq!(guess(32), Some("SHA-256"));
+        assert_eq!(guess(48), Some("SHA-384"));
+        assert_eq!(guess(64), Some("SHA-512"));
+        assert_eq!(guess(0), None);
+        assert_eq!(guess(33), None);
+    }
+
+    #[test]
+    fn it_decodes_a_single_byte() {
+        let hex_s = HexString::from_string(&string_repr().to_uppercase()).unwrap();
+        assert_eq!(hex_s.byte_at(0).unwrap(), 0xcb);
+        assert_eq!(hex_s.byte_at(3).unwrap(), byte_repr()[3]);
+        assert_eq!(hex_s.byte_at(31).unwrap(), 0x0c);
+    }
+
+    #[test]
+    fn it_rejects_decoding_a_byte_past_the_end() {
+        match HexString::from_bytes(&byte_repr()).byte_at(32) {
+            Err(HexStringError::OutOfBounds(range, 32)) => assert_eq!(range, 32..33),
+            other => panic!("did not reject a byte past the end: {:?}", other),
+        }
+        assert!(HexString::empty().byte_at(0).is_err());
+    }
+
+    #[test]
+    fn it_reads_a_single_nibble() {
+        let hex_s = HexString::from_string(&string_repr().to_uppercase()).unwrap();
+        assert_eq!(hex_s.nibble_at(0).unwrap(), 0xc);
+        assert_eq!(hex_s.nibble_at(1).unwrap(), 0xb);
+        assert_eq!(hex_s.nibble_at(63).unwrap(), 0xc);
+    }
+
+    #[test]
+    fn it_rejects_reading_a_nibble_past_the_end() {
+        match HexString::from_bytes(&byte_repr()).nibble_at(64) {
+            Err(HexStringError::OutOfBounds(range, 64)) => assert_eq!(range, 64..65),
+            other => panic!("did not reject a nibble past the end: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_round_trips_through_bits() {
+        let hex_s = HexString::from_string("a501").unwrap();
+        let bits = hex_s.to_bits();
+        assert_eq!(bits, [
+            true, false, true, false, false, true, false, true,
+            false, false, false, false, false, false, false, true,
+        ]);
+        assert_eq!(HexString::from_bits(&bits).unwrap(), hex_s);
+
+        let digest = HexString::from_bytes(&byte_repr());
+        assert_eq!(HexString::from_bits(&digest.to_bits()).unwrap(), digest);
+        assert!(HexString::from_bits(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_rejects_bits_which_do_not_form_whole_bytes() {
+        match HexString::from_bits(&[true; 12]) {
+            Err(HexStringError::NotMultipleOf(12, 8)) => (),
+            other => panic!("did not reject 12 bits: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_counts_occurrences_of_a_byte() {
+        let hex_s = HexString::from_string("00ab0000cd00").unwrap();
+        assert_eq!(hex_s.count_byte(0x00), 4);
+        assert_eq!(hex_s.count_byte(0xab), 1);
+        assert_eq!(hex_s.count_byte(0x0a), 0);
+        assert_eq!(HexString::from_string("ABab").unwrap().count_byte(0xab), 2);
+        assert_eq!(HexString::empty().count_byte(0x00), 0);
+    }
+
+    #[test]
+    fn it_checks_whether_it_contains_a_byte() {
+        let hex_s = HexString::from_string("00ab0000cd00").unwrap();
+        assert!(hex_s.contains_byte(0xcd));
+        assert!(!hex_s.contains_byte(0xdc));
+        assert!(!HexString::from_bytes(&byte_repr()).contains_byte(0x00));
+    }
+
+    #[test]
+    fn it_finds_a_byte_subsequence() {
+        let hex_s = HexString::from_string("00cafebabecafe").unwrap();
+        assert_eq!(hex_s.find_bytes(&[0xca, 0xfe]), Some(1));
+        assert_eq!(hex_s.find_bytes(&[0xbe, 0xca, 0xfe]), Some(4));
+        assert_eq!(hex_s.find_bytes(&[0xfe, 0xca, 0xfe]), None);
+        assert_eq!(hex_s.find_bytes(&[0; 8]), None);
+        assert_eq!(hex_s.find_bytes(&[]), Some(0));
+        assert_eq!(HexString::empty().find_bytes(&[]), Some(0));
+    }
+
+    #[test]
+    fn it_does_not_find_a_subsequence_across_nibbles() {
+        // "afeb" appears in the hex characters, but not at a byte boundary
+        assert_eq!(HexString::from_string("cafeba").unwrap().find_bytes(&[0xaf, 0xeb]), None);
+    }
+
+    #[test]
+    fn it_checks_for_a_byte_prefix() {
+        let png = HexString::from_string("89504E470D0A1A0A0000").unwrap();
+        assert!(png.starts_with_bytes(b"\x89PNG"));
+        assert!(png.starts_with_bytes(&[]));
+        assert!(!png.starts_with_bytes(b"GIF8"));
+        assert!(!HexString::from_string("8950").unwrap().starts_with_bytes(b"\x89PNG"));
+        assert!(!HexString::from_string("0895").unwrap().starts_with_bytes(&[0x89]));
+    }
+
+    #[test]
+    fn it_checks_for_a_byte_suffix() {
+        let hex_s = HexString::from_string("00cafebabe").unwrap();
+        assert!(hex_s.ends_with_bytes(&[0xba, 0xbe]));
+        assert!(hex_s.ends_with_bytes(&[]));
+        assert!(!hex_s.ends_with_bytes(&[0xca, 0xfe]));
+        assert!(!hex_s.ends_with_bytes(&[0xff, 0x00, 0xca, 0xfe, 0xba, 0xbe]));
+    }
+
+    #[test]
+    fn it_round_trips_through_nibble_pairs() {
+        let bcd = HexString::from_string("2024A1").unwrap();
+        let pairs = bcd.nibble_pairs();
+        assert_eq!(pairs, [(2, 0), (2, 4), (10, 1)]);
+        assert_eq!(HexString::from_nibble_pairs(&pairs).unwrap(), "2024a1");
+
+        let digest = HexString::from_bytes(&byte_repr());
+        assert_eq!(HexString::from_nibble_pairs(&digest.nibble_pairs()).unwrap(), digest);
+    }
+
+    #[test]
+    fn it_rejects_nibble_pairs_out_of_range() {
+        match HexString::from_nibble_pairs(&[(1, 2), (3, 16)]) {
+            Err(HexStringError::InvalidNibble(16)) => (),
+            other => panic!("did not reject a nibble of 16: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_decodes_into_a_fixed_size_array() {
+        let digest: [u8; 32] = HexString::from_bytes(&byte_repr()).to_array().unwrap();
+        assert_eq!(digest.to_vec(), byte_repr());
+    }
+
+    #[test]
+    fn it_refuses_to_decode_into_a_wrongly_sized_array() {
+        match HexString::from_bytes(&byte_repr()).to_array::<20>() {
+            Err(HexStringError::LengthMismatch(20, 32)) => (),
+            other => panic!("did not reject a 20 byte array: {:?}", other),
+        }
+        assert!(HexString::from_bytes(&byte_repr()).to_array::<33>().is_err());
+    }
+
+    #[test]
+    fn it_can_be_converted_into_digest_arrays() {
+        use core::convert::TryInto;
+
+        let sha256: [u8; 32] = HexString::from_bytes(&byte_repr()).try_into().unwrap();
+        assert_eq!(sha256.to_vec(), byte_repr());
+
+        let sha1: [u8; 20] = HexString::from_bytes(&byte_repr()[..20]).try_into().unwrap();
+        assert_eq!(sha1.to_vec(), &byte_repr()[..20]);
+
+        let md5: [u8; 16] = HexString::from_bytes(&byte_repr()[..16]).try_into().unwrap();
+        assert_eq!(md5.to_vec(), &byte_repr()[..16]);
+    }
+
+    #[test]
+    fn it_refuses_to_convert_into_wrongly_sized_digest_arrays() {
+        match <[u8; 32]>::try_from(HexString::from_bytes(&byte_repr()[..31])) {
+            Err(HexStringError::LengthMismatch(32, 31)) => (),
+            other => panic!("did not reject a 31 byte value: {:?}", other),
+        }
+        match <[u8; 20]>::try_from(HexString::from_bytes(&byte_repr())) {
+            Err(HexStringError::LengthMismatch(20, 32)) => (),
+            other => panic!("did not reject a 32 byte value: {:?}", other),
+        }
+        match <[u8; 16]>::try_from(HexString::from_bytes(&byte_repr()[..20])) {
+            Err(HexStringError::LengthMismatch(16, 20)) => (),
+            other => panic!("did not reject a 20 byte value: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_builds_a_hex_string_incrementally() {
+        let bytes = byte_repr();
+        let mut builder = HexStringBuilder::new();
+        builder.push_byte(bytes[0]).push_byte(bytes[1]);
+        builder.push_bytes(&bytes[2..20]);
+        builder.push_bytes(&[]);
+        builder.push_bytes(&bytes[20..]);
+        assert_eq!(builder.build(), HexString::from_bytes(&bytes));
+
+        assert_eq!(HexStringBuilder::with_capacity(4).build(), HexString::from_bytes(&[]));
+    }
+
+    #[test]
+    fn it_converts_every_byte_to_the_same_hex_bytes_as_hex_chars() {
+        for b in 0..=255u8 {
+            let [upper, lower] = u8_to_hex_bytes(b);
+            assert_eq!([upper as char, lower as char], u8_to_hex_string(&b));
+        }
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn it_encodes_the_same_with_simd_as_with_the_scalar_encoder() {
+        // a simple xorshift generator, so that the buffer is the same on every run
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let large = (0..100_003).map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        }).collect::<Vec<u8>>();
+        let all_bytes = (0..=255).collect::<Vec<u8>>();
+
+        for input in [&large[..], &all_bytes[..], &byte_repr()[..], &byte_repr()[..21], &byte_repr()[..15], &[]] {
+            let mut simd = String::new();
+            encode_into(input, &mut simd);
+            let mut scalar = String::new();
+            encode_scalar_into(input, &mut scalar);
+            assert_eq!(simd, scalar);
+        }
+    }
+
+    #[cfg(feature = "hex-compat")]
+    #[test]
+    fn it_round_trips_through_the_hex_crate() {
+        let hex_s = HexString::from_string(&string_repr().to_uppercase()).unwrap();
+        let bytes = hex::decode(hex_s.as_str()).unwrap();
+        assert_eq!(bytes, byte_repr());
+        assert_eq!(HexString::from(bytes), *string_repr());
+
+        let encoded = hex_s.to_hex_crate_string();
+        assert_eq!(encoded, hex::encode(byte_repr()));
+        assert_eq!(HexString::try_from(encoded).unwrap(), *string_repr());
+
+        let from_hex: HexString = hex::FromHex::from_hex(string_repr().to_uppercase()).unwrap();
+        assert_eq!(from_hex, *string_repr());
+        assert!(<HexString as hex::FromHex>::from_hex("abc").is_err());
+    }
+
+    #[cfg(feature = "base64")]
+    #[test]
+    fn it_round_trips_through_base64() {
+        // the SHA-256 digest of an empty input
+        let digest = HexString::from_string("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855").unwrap();
+        let base64 = digest.to_base64();
+        assert_eq!(base64, "47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU=");
+        assert_eq!(HexString::from_base64(&base64).unwrap(), digest);
+
+        let hex_s = HexString::from_bytes(&byte_repr());
+        assert_eq!(HexString::from_base64(&hex_s.to_base64()).unwrap(), hex_s);
+        assert_eq!(HexString::from_bytes(&[]).to_base64(), "");
+    }
+
+    #[cfg(feature = "base64")]
+    #[test]
+    fn it_rejects_invalid_base64() {
+        for input in ["47DEQpj8HBSa-_TImW-5JCeuQeRkm5NMpJWZG3hSuFU=", "47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU", "not base64!"] {
+            match HexString::from_base64(input) {
+                Err(HexStringError::InvalidBase64(_)) => (),
+                other => panic!("did not reject {}: {:?}", input, other),
+            }
+        }
+    }
+
+    #[cfg(feature = "crc32")]
+    #[test]
+    fn it_computes_a_crc32_checksum() {
+        // the standard check value for CRC-32 is the checksum of the ASCII digits 1 to 9
+        assert_eq!(HexString::from_bytes(b"123456789").crc32(), 0xcbf4_3926);
+        assert_eq!(HexString::from_bytes(b"The quick brown fox jumps over the lazy dog").crc32(), 0x414f_a339);
+        assert_eq!(HexString::from_bytes(&[]).crc32(), 0);
+    }
+
+    #[test]
+    fn it_compares_against_an_expected_hex_str() {
+        let digest = HexString::from_bytes(&byte_repr());
+        assert!(digest.equals_hex_str(&string_repr().to_uppercase()).unwrap());
+        assert!(digest.equals_hex_str(&format!("0x{}", string_repr())).unwrap());
+        assert!(digest.equals_hex_str(&format!("0X{}", string_repr().to_uppercase())).unwrap());
+        assert!(!digest.equals_hex_str("cbbb").unwrap());
+        assert!(!digest.equals_hex_str(&string_repr().replace('c', "d")).unwrap());
+    }
+
+    #[test]
+    fn it_rejects_comparing_against_an_invalid_hex_str() {
+        let digest = HexString::from_bytes(&byte_repr());
+        match digest.equals_hex_str("0xcbzb") {
+            Err(HexStringError::InvalidCharacterAt { ch: 'z', index: 4 }) => (),
+            other => panic!("did not reject an invalid character: {:?}", other),
+        }
+        assert!(digest.equals_hex_str("0xcbb").is_err());
+    }
+
+    #[test]
+    fn it_counts_differing_bytes() {
+        let hex_s = HexString::from_bytes(&byte_repr());
+        let mut damaged = byte_repr();
+        damaged[0] ^= 0x80;
+        damaged[7] ^= 0x01;
+        damaged[31] = 0xff;
+        assert_eq!(hex_s.byte_diff_count(&damaged).unwrap(), 3);
+        assert_eq!(hex_s.byte_diff_count(&byte_repr()).unwrap(), 0);
+        assert_eq!(HexString::from_string("00ff").unwrap().byte_diff_count(&[0xff, 0x00]).unwrap(), 2);
+    }
+
+    #[test]
+    fn it_refuses_to_count_differences_against_a_different_length() {
+        match HexString::from_bytes(&byte_repr()).byte_diff_count(&byte_repr()[..20]) {
+            Err(HexStringError::LengthMismatch(32, 20)) => (),
+            other => panic!("did not reject a length mismatch: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_compares_in_constant_time() {
+        let a = HexString::from_bytes(&byte_repr());
+        let b = HexString::from_string(&string_repr()).unwrap();
+        let c = HexString::from_string("cbbbc6e19be63efcdd78327d2df850d92375af6a03934f35e47bd02d1b496c0d").unwrap();
+        let short = HexString::from_string("cbbb").unwrap();
+
+        assert_eq!(a.ct_eq(&b), a == b);
+        assert_eq!(a.ct_eq(&c), a == c);
+        assert_eq!(a.ct_eq(&short), a == short);
+        assert!(a.ct_eq(&HexString::from_string(&string_repr().to_uppercase()).unwrap()));
+    }
+
+    #[test]
+    fn it_decodes_every_byte_the_same_without_branching() {
+        for b in 0..=255u8 {
+            let [upper, lower] = u8_to_hex_string(&b);
+            assert_eq!(ct_decode_byte(upper as u8, lower as u8), b);
+            assert_eq!(ct_decode_byte(upper.to_ascii_uppercase() as u8, lower.to_ascii_uppercase() as u8), b);
+        }
+    }
+
+    #[test]
+    fn it_matches_raw_bytes() {
+        let hex_s = HexString::from_string(&string_repr().to_uppercase()).unwrap();
+        assert!(hex_s.matches_bytes(&byte_repr()));
+
+        let mut different = byte_repr();
+        different[31] ^= 1;
+        assert!(!hex_s.matches_bytes(&different));
+    }
+
+    #[test]
+    fn it_does_not_match_raw_bytes_of_a_different_length() {
+        let hex_s = HexString::from_bytes(&byte_repr());
+        assert!(!hex_s.matches_bytes(&byte_repr()[..31]));
+        assert!(!hex_s.matches_bytes(&[byte_repr(), vec![0]].concat()));
+        assert!(!hex_s.matches_bytes(&[]));
+        assert!(HexString::from_bytes(&[]).matches_bytes(&[]));
+    }
+
+    #[test]
+    fn it_can_be_parsed_using_the_parse_function() {
+        let _hex_s = string_repr().parse::<HexString>()
+            .expect("string_repr example should be parsable");
+    }
+
+    #[test]
     fn it_can_fail_for_uneven_length_strings_using_the_parse_function() {
         let hex_s = "abb".parse::<HexString>();
         assert!(hex_s.is_err())
     }
+
+    #[test]
+    fn it_can_be_used_as_a_str_reference() {
+        fn str_len<S: AsRef<str>>(s: S) -> usize { s.as_ref().len() }
+        fn to_owned<S: AsRef<str>>(s: S) -> String { String::from(s.as_ref()) }
+
+        let hex_s = HexString::from_bytes(&byte_repr());
+        assert_eq!(str_len(&hex_s), 64);
+        assert_eq!(to_owned(&hex_s), string_repr());
+    }
+
+    #[test]
+    fn it_references_the_ascii_text_as_bytes() {
+        let hex_s = HexString::from_bytes(&[0xab, 0xcd]);
+        let text: &[u8] = hex_s.as_ref();
+        assert_eq!(text, b"abcd");
+    }
+
+    #[test]
+    fn it_decodes_literals_at_compile_time() {
+        const DIGEST: [u8; 4] = hex!("cbbbC6E1");
+        assert_eq!(DIGEST, [0xcb, 0xbb, 0xc6, 0xe1]);
+        assert_eq!(hex!(""), [0u8; 0]);
+        assert_eq!(hex!("cbbbc6e19be63efcdd78327d2df850d92375af6a03934f35e47bd02d1b496c0c").to_vec(), byte_repr());
+    }
+
+    #[test]
+    fn it_builds_hex_strings_from_literals() {
+        let hex_s = hex_string!("cbbbc6e19be63efcdd78327d2df850d92375af6a03934f35e47bd02d1b496c0c");
+        assert_eq!(hex_s, HexString::from_bytes(&byte_repr()));
+        assert_eq!(hex_string!("ABcd").as_str(), "ABcd");
+    }
+
+    #[test]
+    fn it_dereferences_to_a_str() {
+        let hex_s = HexString::from_bytes(&byte_repr());
+        assert_eq!(hex_s.chars().count(), 64);
+        assert!(hex_s.starts_with("cb"));
+        assert_eq!(&hex_s[..4], "cbbb");
+    }
+
+    #[test]
+    fn it_can_be_converted_from_a_str_slice() {
+        let hex_s = HexString::try_from(string_repr().as_str()).expect("string_repr should be convertable");
+        assert_eq!(hex_s.as_bytes(), byte_repr());
+
+        match HexString::try_from("abcdefg0") {
+            Err(HexStringError::InvalidCharacterAt { ch: 'g', index: 6 }) => (),
+            other => panic!("did not reject a 'g' in the string: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_can_be_converted_from_an_owned_string() {
+        let hex_s = HexString::try_from(string_repr()).expect("string_repr should be convertable");
+        assert_eq!(hex_s.as_bytes(), byte_repr());
+
+        match HexString::try_from(String::from("abcdefg0")) {
+            Err(HexStringError::InvalidCharacterAt { ch: 'g', index: 6 }) => (),
+            other => panic!("did not reject a 'g' in the string: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_can_be_converted_from_a_byte_slice() {
+        let bytes = byte_repr();
+        let hex_s: HexString = bytes.as_slice().into();
+        assert_eq!(hex_s.as_string(), string_repr());
+    }
+
+    #[test]
+    fn it_can_be_converted_from_a_byte_vector() {
+        let hex_s: HexString = byte_repr().into();
+        assert_eq!(hex_s.as_string(), string_repr());
+    }
+
+    #[test]
+    fn it_can_be_compared_to_a_str() {
+        let hex_s = HexString::from_bytes(&[0xab, 0xcd]);
+        assert!(hex_s == "abcd");
+        assert!(hex_s != "abce");
+        assert!(hex_s != "ABCD");
+        assert!(*"abcd" == hex_s);
+        assert!(hex_s == *"abcd");
+    }
+
+    #[test]
+    fn it_can_be_compared_to_a_str_in_either_direction() {
+        let hex_s = HexString::from_bytes(&[0xab, 0xcd]);
+        assert!("abcd" == hex_s);
+        assert!("abce" != hex_s);
+        assert_eq!(hex_s, "abcd");
+        assert_ne!("abc0", hex_s);
+    }
+
+    #[test]
+    fn it_can_be_used_as_a_set_member() {
+        let mut set = std::collections::HashSet::new();
+        set.insert(HexString::from_bytes(&byte_repr()));
+        set.insert(HexString::from_string(&string_repr()).unwrap());
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn it_can_be_looked_up_in_a_map_by_str() {
+        let mut map = std::collections::HashMap::new();
+        map.insert(HexString::from_bytes(&byte_repr()), "digest");
+        assert_eq!(map.get(string_repr().as_str()), Some(&"digest"));
+        assert_eq!(map.get(string_repr().to_uppercase().as_str()), None);
+
+        let mut tree = alloc::collections::BTreeMap::new();
+        tree.insert(HexString::from_bytes(&byte_repr()), "digest");
+        assert_eq!(tree.get(string_repr().as_str()), Some(&"digest"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn it_round_trips_through_serde() {
+        let hex_s = HexString::from_bytes(&byte_repr());
+        let json = serde_json::to_string(&hex_s).expect("HexString should serialize");
+        assert_eq!(json, format!("\"{}\"", string_repr()));
+
+        let parsed: HexString = serde_json::from_str(&json).expect("HexString should deserialize");
+        assert_eq!(parsed, hex_s);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn it_rejects_invalid_strings_through_serde() {
+        let invalid_character = serde_json::from_str::<HexString>("\"abcdefg0\"")
+            .expect_err("a 'g' should not deserialize");
+        assert!(invalid_character.to_string().contains("invalid character 'g' at index 6"));
+
+        let odd_length = serde_json::from_str::<HexString>("\"abc\"")
+            .expect_err("an odd length string should not deserialize");
+        assert!(odd_length.to_string().contains("String length was odd"));
+
+        assert!(serde_json::from_str::<HexString>("12").is_err());
+    }
+
+    #[test]
+    fn it_can_be_converted_from_a_byte_array() {
+        let hex_s: HexString = [0xde, 0xad, 0xbe, 0xef].into();
+        assert_eq!(hex_s, "deadbeef");
+
+        let digest = [0xcbu8, 0xbb, 0xc6, 0xe1];
+        assert_eq!(HexString::from(&digest), "cbbbc6e1");
+    }
+
+    #[test]
+    fn it_sorts_into_byte_order() {
+        let mut digests = [
+            HexString::from_bytes(&[0xff, 0x00]),
+            HexString::from_bytes(&[0x0a, 0xff]),
+            HexString::from_bytes(&[0xa0, 0x00]),
+            HexString::from_bytes(&[0x0a, 0x0f]),
+        ];
+        digests.sort();
+
+        let bytes: Vec<Vec<u8>> = digests.iter().map(|d| d.as_bytes()).collect();
+        assert_eq!(bytes, vec![vec![0x0a, 0x0f], vec![0x0a, 0xff], vec![0xa0, 0x00], vec![0xff, 0x00]]);
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn it_can_be_zeroized() {
+        let mut secret = HexString::from_bytes(&byte_repr());
+        zeroize::Zeroize::zeroize(&mut secret);
+        assert_eq!(secret.as_str(), "");
+        assert!(secret.is_empty());
+    }
+
+    #[test]
+    fn it_redacts_secrets_in_debug_output() {
+        let secret = SecretHexString::new(HexString::from_bytes(&byte_repr()));
+        let debug = format!("{:?}", secret);
+        assert_eq!(debug, "SecretHexString(***redacted***)");
+        assert!(!debug.contains(&string_repr()));
+        assert!(!format!("{:#?}", secret).contains("cbbb"));
+
+        assert_eq!(secret.expose().as_string(), string_repr());
+        assert_eq!(secret.into_inner(), HexString::from_bytes(&byte_repr()));
+    }
+
+    #[test]
+    fn it_can_be_collected_from_bytes() {
+        let hex_s: HexString = byte_repr().into_iter().collect();
+        assert_eq!(hex_s, HexString::from_bytes(&byte_repr()));
+
+        let evens: HexString = (0u8..8).filter(|b| b % 2 == 0).map(|b| b * 0x11).collect();
+        assert_eq!(evens, HexString::from_bytes(&[0x00, 0x22, 0x44, 0x66]));
+    }
+
+    #[test]
+    fn it_can_be_displayed() {
+        assert_eq!(format!("{}", HexString::from_bytes(&[0xab, 0xcd])), "abcd");
+        assert_eq!(HexString::from_bytes(&byte_repr()).to_string(), string_repr());
+    }
+
+    #[test]
+    fn it_displays_a_prefix_with_the_alternate_flag() {
+        let hex_s = HexString::from_string("ABcd").unwrap();
+        assert_eq!(format!("{:#}", hex_s), "0xABcd");
+        assert_eq!(format!("{:#}", HexString::empty()), "0x");
+    }
+
+    #[test]
+    fn it_pads_when_displayed_with_a_width() {
+        let hex_s = HexString::from_bytes(&[0xab, 0xcd]);
+        assert_eq!(format!("{:>10}", hex_s), "      abcd");
+        assert_eq!(format!("{:<10}|", hex_s), "abcd      |");
+        assert_eq!(format!("{:*^8}", hex_s), "**abcd**");
+        assert_eq!(format!("{:>#8}", hex_s), "  0xabcd");
+        assert_eq!(format!("{:2}", hex_s), "abcd");
+    }
+
+    #[test]
+    fn it_formats_with_hex_specifiers() {
+        let hex_s = HexString::from_string("ABcd").unwrap();
+        assert_eq!(format!("{:x}", hex_s), "abcd");
+        assert_eq!(format!("{:X}", hex_s), "ABCD");
+        assert_eq!(format!("{:#x}", hex_s), "0xabcd");
+        assert_eq!(format!("{:#X}", hex_s), "0xABCD");
+        assert_eq!(format!("{:>6X}", hex_s), "  ABCD");
+        assert_eq!(format!("{:X}", HexString::from_bytes(&byte_repr())), string_repr().to_uppercase());
+    }
+
+    #[test]
+    fn it_normalizes_the_case() {
+        let mixed = HexString::from_string("cbbbC6E19BE63efcdd78327d2df850d92375af6a03934f35e47bd02d1b496C0C").unwrap();
+        let lower = HexString::from_string(&string_repr()).unwrap();
+        assert_eq!(mixed.to_upper(), *string_repr().to_uppercase());
+        assert_eq!(mixed.to_lower(), lower);
+        assert_eq!(lower.to_upper().to_lower(), lower);
+        assert_eq!(mixed.to_upper().as_bytes(), mixed.as_bytes());
+    }
+
+    #[test]
+    fn it_canonicalizes_to_lower_case() {
+        let lower = HexString::from_string(&string_repr()).unwrap();
+        let mut upper = HexString::from_string(&string_repr().to_uppercase()).unwrap();
+        assert!(upper != lower);
+
+        upper.canonicalize();
+        assert_eq!(upper, lower);
+        assert_eq!(HexString::from_string("ABcd").unwrap().into_canonical(), "abcd");
+
+        let mut set = std::collections::HashSet::new();
+        set.insert(HexString::from_string("ABCD").unwrap().into_canonical());
+        set.insert(HexString::from_string("abcd").unwrap().into_canonical());
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn it_converts_uppercase_string_to_bytes() {
+        let res = HexString::from_string(&string_repr().to_uppercase())
+            .expect("uppercase string_repr should be parsable");
+        assert_eq!(res.as_bytes(), byte_repr());
+        assert_eq!(res.as_string(), string_repr().to_uppercase());
+    }
+
+    #[test]
+    fn it_converts_mixed_case_string_to_bytes() {
+        let res = HexString::from_string("CbBbC6e1").expect("mixed case string should be parsable");
+        assert_eq!(res.as_bytes(), vec![0xcb, 0xbb, 0xc6, 0xe1]);
+        assert_eq!(res.as_str(), "CbBbC6e1");
+    }
+
+    #[test]
+    fn it_converts_bytes_to_upper_case_string() {
+        let res = HexString::from_bytes(&byte_repr());
+        assert_eq!(res.as_string_upper(), "CBBBC6E19BE63EFCDD78327D2DF850D92375AF6A03934F35E47BD02D1B496C0C");
+        assert_eq!(res.as_string(), string_repr());
+    }
+
+    #[test]
+    fn it_converts_bytes_to_lower_case_string() {
+        let res = HexString::from_bytes(&byte_repr());
+        assert_eq!(res.as_string_lower(), res.as_string());
+
+        let upper = HexString::from_string(&string_repr().to_uppercase()).unwrap();
+        assert_eq!(upper.as_string_lower(), string_repr());
+    }
 }